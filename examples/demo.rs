@@ -2,7 +2,7 @@ use dioxus::prelude::*;
 use dioxus_bulma::prelude::*;
 use dioxus_bulma::components::{
     Title, Subtitle, TitleSize,
-    TabsAlignment, TabsStyle,
+    TabsStyle,
     PaginationAlignment,
     InputType,
     ColumnSize,
@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 /// A Bulma `Box` element — a simple white-on-grey container with a subtle border-radius and shadow.
 ///
@@ -16,6 +17,8 @@ pub struct BulmaBoxProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -24,9 +27,7 @@ pub struct BulmaBoxProps {
 
 #[component]
 pub fn BulmaBox(props: BulmaBoxProps) -> Element {
-    let base_classes = vec!["box"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["box", &props.helpers, &props.class];
     let box_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -51,6 +52,8 @@ pub struct BlockProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -59,9 +62,7 @@ pub struct BlockProps {
 
 #[component]
 pub fn Block(props: BlockProps) -> Element {
-    let base_classes = vec!["block"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["block", &props.helpers, &props.class];
     let block_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
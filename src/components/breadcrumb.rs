@@ -1,6 +1,11 @@
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BreadcrumbSeparator {
@@ -21,6 +26,16 @@ impl BreadcrumbSeparator {
     }
 }
 
+impl BulmaClass for BreadcrumbSeparator {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("has-").unwrap_or_default()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "has-"
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BreadcrumbAlignment {
     Left,
@@ -38,6 +53,27 @@ impl BreadcrumbAlignment {
     }
 }
 
+impl BulmaClass for BreadcrumbAlignment {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(BreadcrumbSeparator, BreadcrumbAlignment);
+
+named_enum!(BreadcrumbSeparator {
+    Arrow => "arrow",
+    Bullet => "bullet",
+    Dot => "dot",
+    Succeeds => "succeeds",
+});
+
+named_enum!(BreadcrumbAlignment {
+    Left => "left",
+    Centered => "centered",
+    Right => "right",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct BreadcrumbProps {
     #[props(default)]
@@ -49,6 +85,8 @@ pub struct BreadcrumbProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -57,18 +95,19 @@ pub struct BreadcrumbProps {
 
 #[component]
 pub fn Breadcrumb(props: BreadcrumbProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = defaults.control_size(&defaults.breadcrumb, props.size);
     let alignment = props.alignment.unwrap_or(BreadcrumbAlignment::Left);
     
-    let base_classes = vec!["breadcrumb"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if alignment != BreadcrumbAlignment::Left { Some(alignment.as_str().to_string()) } else { None },
-        props.separator.map(|s| s.as_str().to_string()),
-        props.class.clone(),
+    let final_class = classes![
+        "breadcrumb",
+        size,
+        alignment,
+        props.separator,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let breadcrumb_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -96,9 +135,15 @@ pub struct BreadcrumbItemProps {
     #[cfg(feature = "router")]
     #[props(default, into)]
     pub to: crate::router_helpers::MaybeNav,
+    /// Derives `active` from the current route when `active` is unset.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub active_match: Option<crate::router_helpers::ActiveMatch>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -107,63 +152,157 @@ pub struct BreadcrumbItemProps {
 
 #[component]
 pub fn BreadcrumbItem(props: BreadcrumbItemProps) -> Element {
+    #[cfg(feature = "router")]
+    let active = crate::router_helpers::resolve_active(props.active, props.active_match.as_ref(), &props.to);
+    #[cfg(not(feature = "router"))]
     let active = props.active.unwrap_or(false);
     
-    let base_classes = vec![];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let item_style = props.style.as_deref().unwrap_or("");
 
-    // Handle router navigation if feature is enabled
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            li {
-                class: "{final_class}",
-                style: "{item_style}",
-                id: props.id.clone(),
-                Link {
-                    to: nav_target,
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
-            }
-        };
-    }
-
     rsx! {
         li {
             class: "{final_class}",
             style: "{item_style}",
             id: props.id.clone(),
-            if let Some(href) = props.href {
-                a {
-                    href: "{href}",
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
-            } else {
-                span {
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
+            {Linkable(LinkableProps {
+                href: props.href,
+                #[cfg(feature = "router")]
+                to: props.to,
+                fallback: LinkFallback::Span,
+                aria_current: active.then(|| "page".to_string()),
+                onclick: props.onclick,
+                children: props.children,
+                ..Default::default()
+            })}
+        }
+    }
+}
+
+/// Labels for the crumbs of an [`AutoBreadcrumb`], implemented by the app's
+/// `Routable` route enum. Both methods have defaults, so an empty impl is
+/// enough to get started:
+///
+/// ```rust,ignore
+/// impl BreadcrumbLabel for Route {
+///     fn breadcrumb_label(&self) -> Option<String> {
+///         match self {
+///             Route::Device { id } => Some(format!("Device #{id}")),
+///             _ => None,
+///         }
+///     }
+///
+///     fn in_breadcrumb(&self) -> bool {
+///         !matches!(self, Route::PageNotFound { .. })
+///     }
+/// }
+/// ```
+#[cfg(feature = "router")]
+pub trait BreadcrumbLabel: dioxus::router::Routable + PartialEq {
+    /// The crumb's label. `None` derives one from the route's last path
+    /// segment (`device-list` becomes "Device list"), or uses the
+    /// breadcrumb's `home_label` for the root.
+    fn breadcrumb_label(&self) -> Option<String> {
+        None
+    }
+
+    /// Whether the route gets a crumb. Return `false` for catch-all routes,
+    /// which would otherwise match every path prefix.
+    fn in_breadcrumb(&self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "router")]
+#[derive(Props, Clone, PartialEq)]
+pub struct AutoBreadcrumbProps<R: BreadcrumbLabel> {
+    /// Label of the root crumb when its route has no label of its own.
+    /// Defaults to "Home".
+    #[props(default)]
+    pub home_label: Option<String>,
+    #[props(default)]
+    pub size: Option<BulmaSize>,
+    #[props(default)]
+    pub alignment: Option<BreadcrumbAlignment>,
+    #[props(default)]
+    pub separator: Option<BreadcrumbSeparator>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    #[props(default)]
+    pub route: std::marker::PhantomData<R>,
+}
+
+/// A [`Breadcrumb`] derived from the current route: one crumb for each path
+/// prefix that parses as a route of `R`, the last one active. Outside a
+/// router the trail is empty.
+///
+/// ```rust,ignore
+/// // On /devices/42: Home › Devices › Device #42
+/// AutoBreadcrumb::<Route> { separator: BreadcrumbSeparator::Succeeds }
+/// ```
+#[cfg(feature = "router")]
+#[component]
+pub fn AutoBreadcrumb<R: BreadcrumbLabel>(props: AutoBreadcrumbProps<R>) -> Element {
+    let home_label = props.home_label.as_deref().unwrap_or("Home");
+    // Crumbs link through the router, so there is nothing to render without one.
+    let crumbs = dioxus::router::try_router()
+        .map(|router| breadcrumb_trail::<R>(&router.full_route_string(), home_label))
+        .unwrap_or_default();
+    let last = crumbs.len().saturating_sub(1);
+
+    rsx! {
+        Breadcrumb {
+            size: props.size,
+            alignment: props.alignment,
+            separator: props.separator,
+            id: props.id.clone(),
+            helpers: props.helpers.clone(),
+            class: props.class.clone(),
+            style: props.style.clone(),
+            for (index, (route, label)) in crumbs.into_iter().enumerate() {
+                BreadcrumbItem { key: "{route}", to: route, active: index == last, "{label}" }
             }
         }
     }
 }
+
+/// The routes and labels of the crumbs for `path`.
+#[cfg(feature = "router")]
+pub fn breadcrumb_trail<R: BreadcrumbLabel>(path: &str, home_label: &str) -> Vec<(R, String)> {
+    let path = path.split(['?', '#']).next().unwrap_or_default();
+    let segments: Vec<&str> = path.split('/').filter(|segment| !segment.is_empty()).collect();
+
+    (0..=segments.len())
+        .filter_map(|depth| {
+            let prefix = format!("/{}", segments[..depth].join("/"));
+            let route = prefix.parse::<R>().ok().filter(R::in_breadcrumb)?;
+            let label = route.breadcrumb_label().unwrap_or_else(|| match depth {
+                0 => home_label.to_string(),
+                _ => humanize_segment(segments[depth - 1]),
+            });
+            Some((route, label))
+        })
+        .collect()
+}
+
+/// `device-list` → "Device list".
+#[cfg(feature = "router")]
+fn humanize_segment(segment: &str) -> String {
+    let words = segment.replace(['-', '_'], " ");
+    let mut chars = words.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => words,
+    }
+}
@@ -1,11 +1,17 @@
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -22,9 +28,14 @@ pub struct ButtonProps {
     pub fullwidth: Option<bool>,
     #[props(default)]
     pub onclick: Option<EventHandler<MouseEvent>>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -37,65 +48,42 @@ pub struct ButtonProps {
 
 #[component]
 pub fn Button(props: ButtonProps) -> Element {
-    let color = props.color.unwrap_or(BulmaColor::Primary);
-    let size = props.size.unwrap_or_default();
-    let outlined = props.outlined.unwrap_or(false);
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.button.color).unwrap_or(BulmaColor::Primary);
+    let size = defaults.control_size(&defaults.button, props.size);
+    let outlined = props.outlined.or(defaults.button.outlined).unwrap_or(false);
     let inverted = props.inverted.unwrap_or(false);
-    let rounded = props.rounded.unwrap_or(false);
+    let rounded = props.rounded.or(defaults.button.rounded).unwrap_or(false);
     let loading = props.loading.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     let fullwidth = props.fullwidth.unwrap_or(false);
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["button"];
-    
-    let optional_classes = vec![
-        Some(format!("is-{}", color.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if outlined { Some("is-outlined".to_string()) } else { None },
-        if inverted { Some("is-inverted".to_string()) } else { None },
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        if loading { Some("is-loading".to_string()) } else { None },
-        if fullwidth { Some("is-fullwidth".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "button",
+        color,
+        props.shade.map(|s| s.fill_class(color)),
+        size,
+        outlined.then_some("is-outlined"),
+        inverted.then_some("is-inverted"),
+        rounded.then_some("is-rounded"),
+        loading.then_some("is-loading"),
+        fullwidth.then_some("is-fullwidth"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
-    
-    let final_class = build_class(&base_classes, &optional_classes);
-    let button_style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            Link {
-                to: nav_target,
-                class: "{final_class}",
-                style: "{button_style}",
-                id: props.id.clone(),
-                onclick: move |evt| {
-                    if !disabled && !loading {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    }
-                },
-                {props.children}
-            }
-        };
-    }
 
-    rsx! {
-        button {
-            class: "{final_class}",
-            style: "{button_style}",
-            id: props.id.clone(),
-            disabled: disabled,
-            onclick: move |evt| {
-                if !disabled && !loading {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                }
-            },
-            {props.children}
-        }
-    }
-}
\ No newline at end of file
+    Linkable(LinkableProps {
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Button,
+        disabled,
+        onclick: if loading { None } else { props.onclick },
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
+}
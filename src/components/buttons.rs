@@ -1,6 +1,10 @@
 use dioxus::prelude::*;
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ButtonsAlignment {
@@ -19,6 +23,20 @@ impl ButtonsAlignment {
     }
 }
 
+impl BulmaClass for ButtonsAlignment {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(ButtonsAlignment);
+
+named_enum!(ButtonsAlignment {
+    Left => "left",
+    Centered => "centered",
+    Right => "right",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ButtonsProps {
     #[props(default)]
@@ -30,6 +48,8 @@ pub struct ButtonsProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -38,20 +58,20 @@ pub struct ButtonsProps {
 
 #[component]
 pub fn Buttons(props: ButtonsProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = props.size.or(defaults.buttons.size).unwrap_or_default();
     let alignment = props.alignment.unwrap_or(ButtonsAlignment::Left);
     let addons = props.addons.unwrap_or(false);
 
-    let base_classes = vec!["buttons"];
-
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if alignment != ButtonsAlignment::Left { Some(alignment.as_class().to_string()) } else { None },
-        if addons { Some("has-addons".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "buttons",
+        size,
+        alignment,
+        addons.then_some("has-addons"),
+        &props.helpers,
+        &props.class,
     ];
 
-    let final_class = build_class(&base_classes, &optional_classes);
     let buttons_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -62,4 +82,4 @@ pub fn Buttons(props: ButtonsProps) -> Element {
             {props.children}
         }
     }
-}
\ No newline at end of file
+}
@@ -1,11 +1,18 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CardProps {
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -14,9 +21,13 @@ pub struct CardProps {
 
 #[component]
 pub fn Card(props: CardProps) -> Element {
-    let base_classes = vec!["card"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let skeleton = props.skeleton.unwrap_or(false);
+    let final_class = classes![
+        "card",
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
+    ];
     let card_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -34,6 +45,8 @@ pub struct CardHeaderProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -42,9 +55,7 @@ pub struct CardHeaderProps {
 
 #[component]
 pub fn CardHeader(props: CardHeaderProps) -> Element {
-    let base_classes = vec!["card-header"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["card-header", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -61,6 +72,8 @@ pub struct CardHeaderTitleProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -69,9 +82,7 @@ pub struct CardHeaderTitleProps {
 
 #[component]
 pub fn CardHeaderTitle(props: CardHeaderTitleProps) -> Element {
-    let base_classes = vec!["card-header-title"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["card-header-title", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -88,6 +99,8 @@ pub struct CardContentProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -96,9 +109,7 @@ pub struct CardContentProps {
 
 #[component]
 pub fn CardContent(props: CardContentProps) -> Element {
-    let base_classes = vec!["card-content"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["card-content", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -115,6 +126,8 @@ pub struct CardFooterProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -123,9 +136,7 @@ pub struct CardFooterProps {
 
 #[component]
 pub fn CardFooter(props: CardFooterProps) -> Element {
-    let base_classes = vec!["card-footer"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["card-footer", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -143,9 +154,15 @@ pub struct CardFooterItemProps {
     pub href: Option<String>,
     #[props(default)]
     pub onclick: Option<EventHandler<MouseEvent>>,
+    /// If present, use router navigation instead of href
+    #[cfg(feature = "router")]
+    #[props(default, into)]
+    pub to: crate::router_helpers::MaybeNav,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -154,35 +171,18 @@ pub struct CardFooterItemProps {
 
 #[component]
 pub fn CardFooterItem(props: CardFooterItemProps) -> Element {
-    let base_classes = vec!["card-footer-item"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
-    let style = props.style.as_deref().unwrap_or("");
-
-    rsx! {
-        if let Some(href) = props.href {
-            a {
-                class: "{final_class}",
-                style: "{style}",
-                href: "{href}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        } else {
-            p {
-                class: "{final_class}",
-                style: "{style}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        }
-    }
+    let final_class = classes!["card-footer-item", &props.helpers, &props.class];
+
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Paragraph,
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
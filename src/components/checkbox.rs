@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct CheckboxProps {
@@ -16,6 +17,8 @@ pub struct CheckboxProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -27,9 +30,7 @@ pub fn Checkbox(props: CheckboxProps) -> Element {
     let checked = props.checked.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["checkbox"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["checkbox", &props.helpers, &props.class];
     let checkbox_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,5 +1,9 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::responsive::{Responsive, TextAlignment};
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ColumnSize {
@@ -58,6 +62,40 @@ impl ColumnSize {
     }
 }
 
+impl BulmaClass for ColumnSize {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(ColumnSize);
+
+named_enum!(ColumnSize {
+    ThreeQuarters => "three-quarters",
+    TwoThirds => "two-thirds",
+    Half => "half",
+    OneThird => "one-third",
+    OneQuarter => "one-quarter",
+    Full => "full",
+    FourFifths => "four-fifths",
+    ThreeFifths => "three-fifths",
+    TwoFifths => "two-fifths",
+    OneFifth => "one-fifth",
+    Narrow => "narrow",
+    One => "1",
+    Two => "2",
+    Three => "3",
+    Four => "4",
+    Five => "5",
+    Six => "6",
+    Seven => "7",
+    Eight => "8",
+    Nine => "9",
+    Ten => "10",
+    Eleven => "11",
+    Twelve => "12",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ColumnsProps {
     #[props(default)]
@@ -72,9 +110,17 @@ pub struct ColumnsProps {
     pub centered: Option<bool>,
     #[props(default)]
     pub vcentered: Option<bool>,
+    /// Hides the element, optionally only at some breakpoints.
+    #[props(default, into)]
+    pub hidden: Responsive<bool>,
+    /// Aligns the element's text, optionally per breakpoint.
+    #[props(default, into)]
+    pub text_align: Responsive<TextAlignment>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -90,19 +136,20 @@ pub fn Columns(props: ColumnsProps) -> Element {
     let centered = props.centered.unwrap_or(false);
     let vcentered = props.vcentered.unwrap_or(false);
     
-    let base_classes = vec!["columns"];
-    
-    let optional_classes = vec![
-        if multiline { Some("is-multiline".to_string()) } else { None },
-        if gapless { Some("is-gapless".to_string()) } else { None },
-        if mobile { Some("is-mobile".to_string()) } else { None },
-        if desktop { Some("is-desktop".to_string()) } else { None },
-        if centered { Some("is-centered".to_string()) } else { None },
-        if vcentered { Some("is-vcentered".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "columns",
+        multiline.then_some("is-multiline"),
+        gapless.then_some("is-gapless"),
+        mobile.then_some("is-mobile"),
+        desktop.then_some("is-desktop"),
+        centered.then_some("is-centered"),
+        vcentered.then_some("is-vcentered"),
+        props.hidden.to_hidden_class(),
+        &props.text_align,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let columns_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -117,15 +164,25 @@ pub fn Columns(props: ColumnsProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ColumnProps {
-    #[props(default)]
-    pub size: Option<ColumnSize>,
-    #[props(default)]
-    pub offset: Option<ColumnSize>,
+    /// Width of the column, optionally per breakpoint.
+    #[props(default, into)]
+    pub size: Responsive<ColumnSize>,
+    /// Empty space left of the column, optionally per breakpoint.
+    #[props(default, into)]
+    pub offset: Responsive<ColumnSize>,
     #[props(default)]
     pub narrow: Option<bool>,
+    /// Hides the element, optionally only at some breakpoints.
+    #[props(default, into)]
+    pub hidden: Responsive<bool>,
+    /// Aligns the element's text, optionally per breakpoint.
+    #[props(default, into)]
+    pub text_align: Responsive<TextAlignment>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -136,16 +193,17 @@ pub struct ColumnProps {
 pub fn Column(props: ColumnProps) -> Element {
     let narrow = props.narrow.unwrap_or(false);
     
-    let base_classes = vec!["column"];
-    
-    let optional_classes = vec![
-        props.size.map(|s| s.as_str().to_string()),
-        props.offset.map(|o| format!("is-offset-{}", o.as_str().strip_prefix("is-").unwrap_or(o.as_str()))),
-        if narrow { Some("is-narrow".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "column",
+        &props.size,
+        props.offset.prefixed("is-offset-"),
+        narrow.then_some("is-narrow"),
+        props.hidden.to_hidden_class(),
+        &props.text_align,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let column_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
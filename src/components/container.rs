@@ -1,5 +1,9 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::responsive::{Responsive, TextAlignment};
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ContainerBreakpoint {
@@ -20,15 +24,38 @@ impl ContainerBreakpoint {
     }
 }
 
+impl BulmaClass for ContainerBreakpoint {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(ContainerBreakpoint);
+
+named_enum!(ContainerBreakpoint {
+    Widescreen => "widescreen",
+    FullHD => "fullhd",
+    MaxDesktop => "max-desktop",
+    MaxWidescreen => "max-widescreen",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ContainerProps {
     #[props(default)]
     pub fluid: Option<bool>,
     #[props(default)]
     pub breakpoint: Option<ContainerBreakpoint>,
+    /// Hides the element, optionally only at some breakpoints.
+    #[props(default, into)]
+    pub hidden: Responsive<bool>,
+    /// Aligns the element's text, optionally per breakpoint.
+    #[props(default, into)]
+    pub text_align: Responsive<TextAlignment>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -39,15 +66,16 @@ pub struct ContainerProps {
 pub fn Container(props: ContainerProps) -> Element {
     let fluid = props.fluid.unwrap_or(false);
     
-    let base_classes = vec!["container"];
-    
-    let optional_classes = vec![
-        if fluid { Some("is-fluid".to_string()) } else { None },
-        props.breakpoint.map(|bp| bp.as_str().to_string()),
-        props.class.clone(),
+    let final_class = classes![
+        "container",
+        fluid.then_some("is-fluid"),
+        props.breakpoint,
+        props.hidden.to_hidden_class(),
+        &props.text_align,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let container_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ContentProps {
@@ -9,6 +11,8 @@ pub struct ContentProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -17,15 +21,16 @@ pub struct ContentProps {
 
 #[component]
 pub fn Content(props: ContentProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = props.size.or(defaults.content.size).unwrap_or_default();
     
-    let base_classes = vec!["content"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "content",
+        size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let content_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
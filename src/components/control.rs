@@ -1,6 +1,7 @@
 use dioxus::prelude::*;
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ControlProps {
@@ -17,6 +18,8 @@ pub struct ControlProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -31,17 +34,17 @@ pub fn Control(props: ControlProps) -> Element {
     let expanded = props.expanded.unwrap_or(false);
     let size = props.size.unwrap_or_default();
     
-    let base_classes = vec!["control"];
-    let optional_classes = vec![
-        if has_icons_left { Some("has-icons-left".to_string()) } else { None },
-        if has_icons_right { Some("has-icons-right".to_string()) } else { None },
-        if loading { Some("is-loading".to_string()) } else { None },
-        if expanded { Some("is-expanded".to_string()) } else { None },
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "control",
+        has_icons_left.then_some("has-icons-left"),
+        has_icons_right.then_some("has-icons-right"),
+        loading.then_some("is-loading"),
+        expanded.then_some("is-expanded"),
+        size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let control_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -0,0 +1,577 @@
+//! A [`Table`] rendered from typed rows and [`TableColumn`] definitions, with
+//! click-to-sort headers.
+//!
+//! ```rust,ignore
+//! DataTable {
+//!     rows: devices(),
+//!     columns: vec![
+//!         TableColumn::new("Name", |device: &Device| rsx! { "{device.name}" })
+//!             .sort_by_key(|device| device.name.clone()),
+//!         TableColumn::new("Uptime", |device: &Device| rsx! { "{device.uptime_days} days" })
+//!             .sort_by_key(|device| device.uptime_days)
+//!             .align(TextAlignment::Right)
+//!             .width("8rem")
+//!             .footer(|devices| {
+//!                 let total: u32 = devices.iter().map(|device| device.uptime_days).sum();
+//!                 rsx! { "{total} days" }
+//!             }),
+//!     ],
+//!     initial_sort: TableSort::ascending(0),
+//!     striped: true,
+//!     empty: rsx! { "No devices registered yet." },
+//! }
+//! ```
+//!
+//! Rows become selectable with a `selection` mode; the selected row keys live
+//! in a signal the app can pass in, and `bulk_actions` appear above the table
+//! while any row is selected:
+//!
+//! ```rust,ignore
+//! let mut selected = use_signal(BTreeSet::new);
+//! rsx! {
+//!     DataTable {
+//!         rows: devices(),
+//!         columns,
+//!         selection: SelectionMode::Multiple,
+//!         row_key: |device: &Device| device.id.to_string(),
+//!         selected,
+//!         bulk_actions: rsx! {
+//!             Button { color: BulmaColor::Danger, onclick: move |_| remove(selected.take()), "Remove" }
+//!         },
+//!     }
+//! }
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::rc::Rc;
+use std::sync::atomic::{self, AtomicUsize};
+
+use dioxus::prelude::*;
+use crate::components::{Level, LevelItem, LevelLeft, Table};
+use crate::responsive::TextAlignment;
+use crate::theme::BulmaSize;
+use crate::helpers::Helpers;
+use crate::enums::{impl_name_display, named_enum, BulmaEnum};
+use crate::classes;
+
+static NEXT_DATA_TABLE_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// How many rows of a [`DataTable`] can be selected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// One row, picked with a radio button.
+    Single,
+    /// Any number of rows, picked with checkboxes. Shift-clicking selects
+    /// the range from the previously clicked row, and the header checkbox
+    /// selects or clears all rows.
+    Multiple,
+}
+
+named_enum!(SelectionMode {
+    Single => "single",
+    Multiple => "multiple",
+});
+
+impl_name_display!(SelectionMode);
+
+/// The keys from `from` to `to` in `keys`, in either direction and
+/// inclusive; just `to` when `from` is not among the keys.
+pub fn key_range<'a>(keys: &'a [String], from: &str, to: &str) -> &'a [String] {
+    let position = |key: &str| keys.iter().position(|candidate| candidate == key);
+    match (position(from), position(to)) {
+        (Some(from), Some(to)) => &keys[from.min(to)..=from.max(to)],
+        (None, Some(to)) => &keys[to..=to],
+        _ => &[],
+    }
+}
+
+/// Direction of a [`TableSort`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+impl SortDirection {
+    /// The header's `aria-sort` value, which is also the variant's name.
+    pub fn aria_sort(&self) -> &'static str {
+        self.variant_name()
+    }
+}
+
+named_enum!(SortDirection {
+    Ascending => "ascending",
+    Descending => "descending",
+});
+
+impl_name_display!(SortDirection);
+
+/// The column a [`DataTable`] is sorted by, as an index into its `columns`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+impl TableSort {
+    pub fn ascending(column: usize) -> Self {
+        Self { column, direction: SortDirection::Ascending }
+    }
+
+    pub fn descending(column: usize) -> Self {
+        Self { column, direction: SortDirection::Descending }
+    }
+
+    /// The sort after clicking `column`'s header: ascending, then
+    /// descending, then unsorted.
+    pub fn toggle(current: Option<TableSort>, column: usize) -> Option<TableSort> {
+        match current {
+            Some(sort) if sort.column == column => match sort.direction {
+                SortDirection::Ascending => Some(Self::descending(column)),
+                SortDirection::Descending => None,
+            },
+            _ => Some(Self::ascending(column)),
+        }
+    }
+}
+
+type CompareRows<T> = Rc<dyn Fn(&T, &T) -> Ordering>;
+type RenderFooter<T> = Rc<dyn Fn(&[T]) -> Element>;
+type KeyRow<T> = Rc<dyn Fn(&T) -> String>;
+
+/// One column of a [`DataTable`]: a header and a cell renderer, optionally
+/// sortable, aligned, sized and totalled in a footer.
+pub struct TableColumn<T> {
+    header: String,
+    render: Rc<dyn Fn(&T) -> Element>,
+    compare: Option<CompareRows<T>>,
+    alignment: Option<TextAlignment>,
+    width: Option<String>,
+    footer: Option<RenderFooter<T>>,
+}
+
+impl<T> TableColumn<T> {
+    /// A column titled `header` whose cells are rendered by `render`.
+    pub fn new(header: impl Into<String>, render: impl Fn(&T) -> Element + 'static) -> Self {
+        Self {
+            header: header.into(),
+            render: Rc::new(render),
+            compare: None,
+            alignment: None,
+            width: None,
+            footer: None,
+        }
+    }
+
+    /// Makes the column sortable by comparing rows with `compare`.
+    pub fn sort_by(mut self, compare: impl Fn(&T, &T) -> Ordering + 'static) -> Self {
+        self.compare = Some(Rc::new(compare));
+        self
+    }
+
+    /// Makes the column sortable by the key `key` extracts from each row.
+    pub fn sort_by_key<K: Ord>(self, key: impl Fn(&T) -> K + 'static) -> Self {
+        self.sort_by(move |a, b| key(a).cmp(&key(b)))
+    }
+
+    /// Aligns the header, cells and footer of the column.
+    pub fn align(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = Some(alignment);
+        self
+    }
+
+    /// Any CSS width, e.g. `"8rem"` or `"20%"`.
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Renders the column's footer cell from all rows, e.g. a total.
+    pub fn footer(mut self, footer: impl Fn(&[T]) -> Element + 'static) -> Self {
+        self.footer = Some(Rc::new(footer));
+        self
+    }
+
+    pub fn header(&self) -> &str {
+        &self.header
+    }
+
+    pub fn is_sortable(&self) -> bool {
+        self.compare.is_some()
+    }
+
+    /// The `class` of the column's cells, left out when it is unaligned.
+    fn cell_class(&self) -> Option<String> {
+        Some(classes![self.alignment]).filter(|class| !class.is_empty())
+    }
+}
+
+// Derived impls would require `T: Clone` and `T: PartialEq`; columns only
+// hold shared closures.
+impl<T> Clone for TableColumn<T> {
+    fn clone(&self) -> Self {
+        Self {
+            header: self.header.clone(),
+            render: self.render.clone(),
+            compare: self.compare.clone(),
+            alignment: self.alignment,
+            width: self.width.clone(),
+            footer: self.footer.clone(),
+        }
+    }
+}
+
+impl<T> PartialEq for TableColumn<T> {
+    fn eq(&self, other: &Self) -> bool {
+        fn same<F: ?Sized>(a: &Option<Rc<F>>, b: &Option<Rc<F>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+
+        self.header == other.header
+            && Rc::ptr_eq(&self.render, &other.render)
+            && same(&self.compare, &other.compare)
+            && self.alignment == other.alignment
+            && self.width == other.width
+            && same(&self.footer, &other.footer)
+    }
+}
+
+impl<T> fmt::Debug for TableColumn<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TableColumn")
+            .field("header", &self.header)
+            .field("sortable", &self.is_sortable())
+            .field("alignment", &self.alignment)
+            .field("width", &self.width)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Derives the key identifying a row of a [`DataTable`] in its selection.
+/// Closures taking `&T` convert into it; the default keys rows by their
+/// index in `rows`.
+pub struct RowKey<T>(Option<KeyRow<T>>);
+
+impl<T> RowKey<T> {
+    pub fn new(key: impl Fn(&T) -> String + 'static) -> Self {
+        Self(Some(Rc::new(key)))
+    }
+
+    /// The key of `row`, found at `index` in the table's `rows`.
+    pub fn key(&self, index: usize, row: &T) -> String {
+        match &self.0 {
+            Some(key) => key(row),
+            None => index.to_string(),
+        }
+    }
+}
+
+impl<T> Default for RowKey<T> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<T, F: Fn(&T) -> String + 'static> From<F> for RowKey<T> {
+    fn from(key: F) -> Self {
+        Self::new(key)
+    }
+}
+
+impl<T> Clone for RowKey<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> PartialEq for RowKey<T> {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T> fmt::Debug for RowKey<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RowKey(..)")
+    }
+}
+
+/// The order in which a [`DataTable`] shows `rows` under `sort`, as indices
+/// into `rows`. Sorting is stable; unsortable columns keep the given order.
+pub fn sorted_row_order<T>(rows: &[T], columns: &[TableColumn<T>], sort: Option<TableSort>) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..rows.len()).collect();
+    if let Some(sort) = sort
+        && let Some(compare) = columns.get(sort.column).and_then(|column| column.compare.as_ref())
+    {
+        order.sort_by(|&a, &b| match sort.direction {
+            SortDirection::Ascending => compare(&rows[a], &rows[b]),
+            SortDirection::Descending => compare(&rows[b], &rows[a]),
+        });
+    }
+    order
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct DataTableProps<T: Clone + PartialEq + 'static> {
+    pub rows: Vec<T>,
+    pub columns: Vec<TableColumn<T>>,
+    /// Sort applied until a header is clicked.
+    #[props(default)]
+    pub initial_sort: Option<TableSort>,
+    /// Called with the new sort when a sortable header is clicked; `None`
+    /// when the table returns to the rows' own order.
+    #[props(default)]
+    pub on_sort: Option<EventHandler<Option<TableSort>>>,
+    /// Shown in place of the rows when there are none. Defaults to
+    /// "No entries".
+    #[props(default)]
+    pub empty: Option<Element>,
+    /// Adds a column of checkboxes or radio buttons for selecting rows.
+    #[props(default)]
+    pub selection: Option<SelectionMode>,
+    /// Keys of the selected rows. Pass a signal to read or change the
+    /// selection from outside the table.
+    #[props(default)]
+    pub selected: Option<Signal<BTreeSet<String>>>,
+    /// The key identifying a row in `selected`. Defaults to the row's index
+    /// in `rows`.
+    #[props(default, into)]
+    pub row_key: RowKey<T>,
+    /// Shown above the table, next to the selection count, while any row is
+    /// selected.
+    #[props(default)]
+    pub bulk_actions: Option<Element>,
+    #[props(default)]
+    pub bordered: Option<bool>,
+    #[props(default)]
+    pub striped: Option<bool>,
+    #[props(default)]
+    pub narrow: Option<bool>,
+    #[props(default)]
+    pub hoverable: Option<bool>,
+    #[props(default)]
+    pub fullwidth: Option<bool>,
+    #[props(default)]
+    pub size: Option<BulmaSize>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+}
+
+/// A [`Table`] with a header row, one row per item of `rows` and, when any
+/// column has a footer, a footer row.
+///
+/// Sortable headers are buttons that toggle between ascending, descending
+/// and unsorted order when clicked, and show the current direction with an arrow and
+/// `aria-sort`. Selected rows get Bulma's `is-selected` class.
+#[component]
+pub fn DataTable<T: Clone + PartialEq + 'static>(props: DataTableProps<T>) -> Element {
+    let mut sort = use_signal(|| props.initial_sort);
+    let current_sort = sort();
+    let order = sorted_row_order(&props.rows, &props.columns, current_sort);
+    let on_sort = props.on_sort;
+    let has_footer = props.columns.iter().any(|column| column.footer.is_some());
+
+    let table_id = use_hook(|| format!("data-table-{}", NEXT_DATA_TABLE_ID.fetch_add(1, atomic::Ordering::Relaxed)));
+    let own_selected = use_signal(BTreeSet::new);
+    let mut selected = props.selected.unwrap_or(own_selected);
+    let mut anchor = use_signal(|| None::<String>);
+    let selection = props.selection;
+
+    // Keys of the rows in display order.
+    let keys: Vec<String> = order
+        .iter()
+        .map(|&index| props.row_key.key(index, &props.rows[index]))
+        .collect();
+    let selected_keys = selected.read();
+    let selected_count = keys.iter().filter(|key| selected_keys.contains(*key)).count();
+    let all_selected = !keys.is_empty() && selected_count == keys.len();
+    let partly_selected = selected_count > 0 && !all_selected;
+
+    let select_all_id = format!("{table_id}-select-all");
+    let watched_id = select_all_id.clone();
+    use_effect(use_reactive!(|partly_selected| {
+        // `indeterminate` is a property without an HTML attribute.
+        document::eval(&format!(
+            "const checkbox = document.getElementById('{watched_id}'); if (checkbox) checkbox.indeterminate = {partly_selected};"
+        ));
+    }));
+
+    let all_keys = keys.clone();
+    let toggle_all = move |_| {
+        let mut selected = selected.write();
+        if all_keys.iter().all(|key| selected.contains(key)) {
+            for key in &all_keys {
+                selected.remove(key);
+            }
+        } else {
+            selected.extend(all_keys.iter().cloned());
+        }
+    };
+
+    let row_keys = keys.clone();
+    let click_row = move |key: String, shift: bool| {
+        let mut selected = selected.write();
+        match selection {
+            Some(SelectionMode::Single) => {
+                selected.clear();
+                selected.insert(key.clone());
+            }
+            Some(SelectionMode::Multiple) => {
+                let select = !selected.contains(&key);
+                let range = match anchor.peek().as_deref() {
+                    Some(from) if shift => key_range(&row_keys, from, &key),
+                    _ => std::slice::from_ref(&key),
+                };
+                for key in range {
+                    if select {
+                        selected.insert(key.clone());
+                    } else {
+                        selected.remove(key);
+                    }
+                }
+            }
+            None => {}
+        }
+        anchor.set(Some(key));
+    };
+
+    let column_count = props.columns.len().max(1) + usize::from(selection.is_some());
+    let toolbar = props.bulk_actions.clone().filter(|_| selection.is_some() && selected_count > 0);
+
+    rsx! {
+        if let Some(actions) = toolbar {
+            Level { class: "mb-3",
+                LevelLeft {
+                    LevelItem { "{selected_count} selected" }
+                    LevelItem { {actions} }
+                }
+            }
+        }
+        Table {
+            bordered: props.bordered,
+            striped: props.striped,
+            narrow: props.narrow,
+            hoverable: props.hoverable,
+            fullwidth: props.fullwidth,
+            size: props.size,
+            id: props.id.clone(),
+            helpers: props.helpers.clone(),
+            class: props.class.clone(),
+            style: props.style.clone(),
+            thead {
+                tr {
+                    match selection {
+                        Some(SelectionMode::Multiple) => rsx! {
+                            th { style: "width: 1%",
+                                input {
+                                    id: "{select_all_id}",
+                                    r#type: "checkbox",
+                                    "aria-label": "Select all rows",
+                                    checked: all_selected,
+                                    disabled: keys.is_empty(),
+                                    onclick: toggle_all,
+                                }
+                            }
+                        },
+                        Some(SelectionMode::Single) => rsx! { th { style: "width: 1%" } },
+                        None => rsx! {},
+                    }
+                    for (index, column) in props.columns.iter().enumerate() {
+                        th {
+                            class: column.cell_class(),
+                            style: column.width.as_ref().map(|width| format!("width: {width}")),
+                            "aria-sort": column.is_sortable().then(|| match current_sort {
+                                Some(sort) if sort.column == index => sort.direction.aria_sort(),
+                                _ => "none",
+                            }),
+                            if column.is_sortable() {
+                                button {
+                                    class: "button is-ghost px-0",
+                                    r#type: "button",
+                                    onclick: move |_| {
+                                        let next = TableSort::toggle(*sort.peek(), index);
+                                        sort.set(next);
+                                        if let Some(handler) = on_sort {
+                                            handler.call(next);
+                                        }
+                                    },
+                                    "{column.header} "
+                                    span { class: "has-text-grey-light", "aria-hidden": "true",
+                                        match current_sort {
+                                            Some(TableSort { column, direction: SortDirection::Ascending }) if column == index => "↑",
+                                            Some(TableSort { column, direction: SortDirection::Descending }) if column == index => "↓",
+                                            _ => "↕",
+                                        }
+                                    }
+                                }
+                            } else {
+                                "{column.header}"
+                            }
+                        }
+                    }
+                }
+            }
+            tbody {
+                if order.is_empty() {
+                    tr {
+                        td { class: "has-text-centered has-text-grey", colspan: "{column_count}",
+                            {props.empty.clone().unwrap_or_else(|| rsx! { "No entries" })}
+                        }
+                    }
+                }
+                for (row, key) in order.iter().map(|&index| &props.rows[index]).zip(keys) {
+                    tr { key: "{key}", class: selected_keys.contains(&key).then_some("is-selected"),
+                        if selection.is_some() {
+                            td {
+                                input {
+                                    r#type: if selection == Some(SelectionMode::Single) { "radio" } else { "checkbox" },
+                                    name: "{table_id}-selection",
+                                    "aria-label": "Select row",
+                                    checked: selected_keys.contains(&key),
+                                    onclick: {
+                                        let mut click_row = click_row.clone();
+                                        let key = key.clone();
+                                        move |evt: MouseEvent| click_row(key.clone(), evt.modifiers().shift())
+                                    },
+                                }
+                            }
+                        }
+                        for column in props.columns.iter() {
+                            td { class: column.cell_class(), {(column.render)(row)} }
+                        }
+                    }
+                }
+            }
+            if has_footer {
+                tfoot {
+                    tr {
+                        if selection.is_some() {
+                            th {}
+                        }
+                        for column in props.columns.iter() {
+                            th { class: column.cell_class(),
+                                {column.footer.as_ref().map(|footer| footer(&props.rows))}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct DeleteProps {
@@ -11,6 +13,8 @@ pub struct DeleteProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -18,15 +22,16 @@ pub struct DeleteProps {
 
 #[component]
 pub fn Delete(props: DeleteProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = props.size.or(defaults.delete.size).unwrap_or_default();
     
-    let base_classes = vec!["delete"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "delete",
+        size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let delete_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
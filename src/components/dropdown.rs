@@ -1,5 +1,9 @@
-use crate::utils::build_class;
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DropdownTrigger {
@@ -7,6 +11,22 @@ pub enum DropdownTrigger {
     Click,
 }
 
+impl BulmaClass for DropdownTrigger {
+    fn name(&self) -> &'static str {
+        match self {
+            DropdownTrigger::Hover => "hoverable",
+            DropdownTrigger::Click => "",
+        }
+    }
+}
+
+impl_class_display!(DropdownTrigger);
+
+named_enum!(DropdownTrigger {
+    Hover => "hover",
+    Click => "click",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct DropdownProps {
     #[props(default)]
@@ -20,6 +40,8 @@ pub struct DropdownProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -33,16 +55,16 @@ pub fn Dropdown(props: DropdownProps) -> Element {
     let right = props.right.unwrap_or(false);
     let up = props.up.unwrap_or(false);
     
-    let base_classes = vec!["dropdown"];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        if hoverable { Some("is-hoverable".to_string()) } else { None },
-        if right { Some("is-right".to_string()) } else { None },
-        if up { Some("is-up".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "dropdown",
+        active.then_some("is-active"),
+        hoverable.then_some("is-hoverable"),
+        right.then_some("is-right"),
+        up.then_some("is-up"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let dropdown_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -62,6 +84,8 @@ pub struct DropdownTriggerProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -70,9 +94,7 @@ pub struct DropdownTriggerProps {
 
 #[component]
 pub fn DropdownTrigger(props: DropdownTriggerProps) -> Element {
-    let base_classes = vec!["dropdown-trigger"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["dropdown-trigger", &props.helpers, &props.class];
     let trigger_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -95,6 +117,8 @@ pub struct DropdownMenuProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -103,9 +127,7 @@ pub struct DropdownMenuProps {
 
 #[component]
 pub fn DropdownMenu(props: DropdownMenuProps) -> Element {
-    let base_classes = vec!["dropdown-menu"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["dropdown-menu", &props.helpers, &props.class];
     let menu_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -137,6 +159,8 @@ pub struct DropdownItemProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -147,61 +171,25 @@ pub struct DropdownItemProps {
 pub fn DropdownItem(props: DropdownItemProps) -> Element {
     let active = props.active.unwrap_or(false);
     
-    let base_classes = vec!["dropdown-item"];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "dropdown-item",
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let item_style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            Link {
-                to: nav_target,
-                class: "{final_class}",
-                style: "{item_style}",
-                id: props.id.clone(),
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        };
-    }
-
-    rsx! {
-        if let Some(href) = props.href {
-            a {
-                class: "{final_class}",
-                style: "{item_style}",
-                id: props.id.clone(),
-                href: "{href}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        } else {
-            div {
-                class: "{final_class}",
-                style: "{item_style}",
-                id: props.id.clone(),
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        }
-    }
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Div,
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -209,6 +197,8 @@ pub struct DropdownDividerProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -216,9 +206,7 @@ pub struct DropdownDividerProps {
 
 #[component]
 pub fn DropdownDivider(props: DropdownDividerProps) -> Element {
-    let base_classes = vec!["dropdown-divider"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["dropdown-divider", &props.helpers, &props.class];
     let divider_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
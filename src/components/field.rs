@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct FieldProps {
@@ -12,6 +13,8 @@ pub struct FieldProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -24,15 +27,15 @@ pub fn Field(props: FieldProps) -> Element {
     let addons = props.addons.unwrap_or(false);
     let horizontal = props.horizontal.unwrap_or(false);
     
-    let base_classes = vec!["field"];
-    let optional_classes = vec![
-        if grouped { Some("is-grouped".to_string()) } else { None },
-        if addons { Some("has-addons".to_string()) } else { None },
-        if horizontal { Some("is-horizontal".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "field",
+        grouped.then_some("is-grouped"),
+        addons.then_some("has-addons"),
+        horizontal.then_some("is-horizontal"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let field_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -50,6 +53,8 @@ pub struct LabelProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -58,9 +63,7 @@ pub struct LabelProps {
 
 #[component]
 pub fn Label(props: LabelProps) -> Element {
-    let base_classes = vec!["label"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["label", &props.helpers, &props.class];
     let label_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -77,9 +80,14 @@ pub fn Label(props: LabelProps) -> Element {
 pub struct HelpProps {
     #[props(default)]
     pub color: Option<crate::theme::BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<crate::theme::ColorShade>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -88,13 +96,14 @@ pub struct HelpProps {
 
 #[component]
 pub fn Help(props: HelpProps) -> Element {
-    let base_classes = vec!["help"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        props.class.clone(),
+    let final_class = classes![
+        "help",
+        props.color,
+        props.color.zip(props.shade).map(|(c, s)| s.text_class(c)),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let help_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
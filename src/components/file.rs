@@ -1,11 +1,16 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct FileProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -33,6 +38,8 @@ pub struct FileProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -41,7 +48,9 @@ pub struct FileProps {
 
 #[component]
 pub fn File(props: FileProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.file.color);
+    let size = defaults.control_size(&defaults.file, props.size);
     let boxed = props.boxed.unwrap_or(false);
     let centered = props.centered.unwrap_or(false);
     let right = props.right.unwrap_or(false);
@@ -50,19 +59,20 @@ pub fn File(props: FileProps) -> Element {
     let multiple = props.multiple.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["file"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if boxed { Some("is-boxed".to_string()) } else { None },
-        if centered { Some("is-centered".to_string()) } else { None },
-        if right { Some("is-right".to_string()) } else { None },
-        if fullwidth { Some("is-fullwidth".to_string()) } else { None },
-        if has_name { Some("has-name".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "file",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.background_class(c)),
+        size,
+        boxed.then_some("is-boxed"),
+        centered.then_some("is-centered"),
+        right.then_some("is-right"),
+        fullwidth.then_some("is-fullwidth"),
+        has_name.then_some("has-name"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let file_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
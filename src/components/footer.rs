@@ -0,0 +1,193 @@
+use dioxus::prelude::*;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::components::{Column, Columns, Container, Linkable, LinkableProps};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterProps {
+    /// Background color; the text uses the color's invert.
+    #[props(default)]
+    pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`. `Light` and `Dark`
+    /// pair the shaded background with the opposite shade for the text.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
+    /// `Small` tightens the footer's vertical padding, `Medium` and `Large`
+    /// widen it.
+    #[props(default)]
+    pub size: Option<BulmaSize>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// Bulma's `footer` layout element.
+#[component]
+pub fn Footer(props: FooterProps) -> Element {
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.footer.color);
+    let size = props.size.or(defaults.footer.size).unwrap_or_default();
+
+    let final_class = classes![
+        "footer",
+        color.map(|c| match props.shade {
+            Some(shade) => shade.background_class(c),
+            None => format!("has-background-{0} has-text-{0}-invert", c.as_str()),
+        }),
+        match size {
+            BulmaSize::Small => "py-4",
+            BulmaSize::Normal => "",
+            BulmaSize::Medium => "py-6",
+            BulmaSize::Large => "py-6 px-6",
+        },
+        &props.helpers,
+        &props.class,
+    ];
+
+    let footer_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        footer {
+            class: "{final_class}",
+            style: "{footer_style}",
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
+
+/// One link in a [`FooterLinkGroup`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FooterLink {
+    pub label: String,
+    pub href: Option<String>,
+    /// Router target, used instead of `href` when set.
+    #[cfg(feature = "router")]
+    pub to: crate::router_helpers::MaybeNav,
+}
+
+impl FooterLink {
+    /// A link without a destination yet; set one with [`href`](Self::href)
+    /// or, with the `router` feature, [`to`](Self::to).
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: None,
+            #[cfg(feature = "router")]
+            to: Default::default(),
+        }
+    }
+
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    #[cfg(feature = "router")]
+    pub fn to(mut self, to: impl Into<crate::router_helpers::MaybeNav>) -> Self {
+        self.to = to.into();
+        self
+    }
+}
+
+/// A titled column of links in a [`FooterColumns`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct FooterLinkGroup {
+    pub title: String,
+    pub links: Vec<FooterLink>,
+}
+
+impl FooterLinkGroup {
+    pub fn new(title: impl Into<String>) -> Self {
+        Self { title: title.into(), links: Vec::new() }
+    }
+
+    pub fn link(mut self, link: FooterLink) -> Self {
+        self.links.push(link);
+        self
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FooterColumnsProps {
+    /// One column per group, in order.
+    pub groups: Vec<FooterLinkGroup>,
+    #[props(default)]
+    pub color: Option<BulmaColor>,
+    #[props(default)]
+    pub shade: Option<ColorShade>,
+    #[props(default)]
+    pub size: Option<BulmaSize>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    /// Rendered below the columns, e.g. a copyright line.
+    pub children: Element,
+}
+
+/// A [`Footer`] laid out as one column of links per group. Links render
+/// through [`Linkable`], so `to` targets navigate through the router and
+/// `href`s become plain anchors.
+///
+/// ```rust,ignore
+/// FooterColumns {
+///     groups: vec![
+///         FooterLinkGroup::new("Product")
+///             .link(FooterLink::new("Pricing").to(Route::Pricing))
+///             .link(FooterLink::new("Changelog").href("/changelog")),
+///         FooterLinkGroup::new("Company")
+///             .link(FooterLink::new("About").to(Route::About)),
+///     ],
+///     p { class: "has-text-centered", "© 2026 Example Inc." }
+/// }
+/// ```
+#[component]
+pub fn FooterColumns(props: FooterColumnsProps) -> Element {
+    rsx! {
+        Footer {
+            color: props.color,
+            shade: props.shade,
+            size: props.size,
+            id: props.id.clone(),
+            helpers: props.helpers.clone(),
+            class: props.class.clone(),
+            style: props.style.clone(),
+            Container {
+                Columns {
+                    for group in props.groups.iter() {
+                        Column {
+                            p { class: "has-text-weight-semibold mb-2", "{group.title}" }
+                            ul {
+                                for link in group.links.iter() {
+                                    li {
+                                        {Linkable(LinkableProps {
+                                            href: link.href.clone(),
+                                            #[cfg(feature = "router")]
+                                            to: link.to.clone(),
+                                            children: rsx! { "{link.label}" },
+                                            ..Default::default()
+                                        })}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                {props.children}
+            }
+        }
+    }
+}
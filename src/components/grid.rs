@@ -0,0 +1,283 @@
+//! Bulma 1.0's CSS-grid layout: the smart [`Grid`], the [`FixedGrid`] with a
+//! set number of columns per breakpoint, and the [`Cell`]s placed in them.
+//!
+//! ```rust,ignore
+//! // As many 12rem-wide columns as fit.
+//! Grid { col_min: 12, gap: 1.5,
+//!     Cell { "One" }
+//!     Cell { col_span: 2, "Two columns wide" }
+//! }
+//!
+//! // Two columns on mobile, four from tablet on, six on desktop.
+//! FixedGrid {
+//!     cols: Responsive::new(GridColumns::Is2)
+//!         .tablet(GridColumns::Is4)
+//!         .desktop(GridColumns::Is6),
+//!     Cell { row_span: 2, "Tall" }
+//!     Cell { col_start: 3, "Starts in the third column" }
+//! }
+//! ```
+//!
+//! See <https://bulma.io/documentation/grid/smart-grid/>.
+
+use dioxus::prelude::*;
+use crate::responsive::Responsive;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
+
+/// Number of columns of a [`FixedGrid`], rendered as `has-<n>-cols`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridColumns {
+    Is1,
+    Is2,
+    Is3,
+    Is4,
+    Is5,
+    Is6,
+    Is7,
+    Is8,
+    Is9,
+    Is10,
+    Is11,
+    Is12,
+}
+
+impl GridColumns {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GridColumns::Is1 => "has-1-cols",
+            GridColumns::Is2 => "has-2-cols",
+            GridColumns::Is3 => "has-3-cols",
+            GridColumns::Is4 => "has-4-cols",
+            GridColumns::Is5 => "has-5-cols",
+            GridColumns::Is6 => "has-6-cols",
+            GridColumns::Is7 => "has-7-cols",
+            GridColumns::Is8 => "has-8-cols",
+            GridColumns::Is9 => "has-9-cols",
+            GridColumns::Is10 => "has-10-cols",
+            GridColumns::Is11 => "has-11-cols",
+            GridColumns::Is12 => "has-12-cols",
+        }
+    }
+}
+
+impl BulmaClass for GridColumns {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("has-").unwrap_or_default()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "has-"
+    }
+}
+
+impl_class_display!(GridColumns);
+
+named_enum!(GridColumns {
+    Is1 => "1",
+    Is2 => "2",
+    Is3 => "3",
+    Is4 => "4",
+    Is5 => "5",
+    Is6 => "6",
+    Is7 => "7",
+    Is8 => "8",
+    Is9 => "9",
+    Is10 => "10",
+    Is11 => "11",
+    Is12 => "12",
+});
+
+/// `<prefix><gap>` for Bulma's `0`–`8` gap scale, which goes in steps of
+/// `0.5`. Gaps are rounded to the nearest step and clamped to the scale.
+fn gap_class(prefix: &str, gap: Option<f32>) -> Option<String> {
+    let steps = (gap?.clamp(0.0, 8.0) * 2.0).round() as u8;
+    Some(if steps.is_multiple_of(2) {
+        format!("{prefix}{}", steps / 2)
+    } else {
+        format!("{prefix}{}.5", steps / 2)
+    })
+}
+
+/// `<prefix><n>` with `n` clamped to `1..=max`.
+fn placement_class(prefix: &str, value: Option<u8>, max: u8) -> Option<String> {
+    value.map(|value| format!("{prefix}{}", value.clamp(1, max)))
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct GridProps {
+    /// Minimum column width, from `1` (1.5rem) to `32` (48rem). Columns are
+    /// added as space allows.
+    #[props(default)]
+    pub col_min: Option<u8>,
+    /// Gap between cells on Bulma's `0`–`8` scale, in steps of `0.5`.
+    #[props(default)]
+    pub gap: Option<f32>,
+    /// Gap between columns; overrides `gap` horizontally.
+    #[props(default)]
+    pub column_gap: Option<f32>,
+    /// Gap between rows; overrides `gap` vertically.
+    #[props(default)]
+    pub row_gap: Option<f32>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// Bulma's smart grid: as many columns as fit, each at least `col_min` wide.
+#[component]
+pub fn Grid(props: GridProps) -> Element {
+    let final_class = classes![
+        "grid",
+        placement_class("is-col-min-", props.col_min, 32),
+        gap_class("is-gap-", props.gap),
+        gap_class("is-column-gap-", props.column_gap),
+        gap_class("is-row-gap-", props.row_gap),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let grid_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{grid_style}",
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct FixedGridProps {
+    /// Number of columns, optionally per breakpoint.
+    #[props(default, into)]
+    pub cols: Responsive<GridColumns>,
+    /// Lets Bulma pick the column count for each breakpoint. Ignored when
+    /// `cols` is set.
+    #[props(default)]
+    pub auto_count: Option<bool>,
+    /// Gap between cells on Bulma's `0`–`8` scale, in steps of `0.5`.
+    #[props(default)]
+    pub gap: Option<f32>,
+    /// Gap between columns; overrides `gap` horizontally.
+    #[props(default)]
+    pub column_gap: Option<f32>,
+    /// Gap between rows; overrides `gap` vertically.
+    #[props(default)]
+    pub row_gap: Option<f32>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// A grid with a fixed number of columns. Renders the `fixed-grid` wrapper
+/// and its inner `grid`; `id`, `helpers`, `class` and `style` apply to the
+/// wrapper.
+#[component]
+pub fn FixedGrid(props: FixedGridProps) -> Element {
+    let auto_count = props.auto_count.unwrap_or(false) && props.cols.is_empty();
+
+    let final_class = classes![
+        "fixed-grid",
+        &props.cols,
+        auto_count.then_some("has-auto-count"),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let grid_class = classes![
+        "grid",
+        gap_class("is-gap-", props.gap),
+        gap_class("is-column-gap-", props.column_gap),
+        gap_class("is-row-gap-", props.row_gap),
+    ];
+
+    let fixed_grid_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{fixed_grid_style}",
+            id: props.id.clone(),
+            div {
+                class: "{grid_class}",
+                {props.children}
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct CellProps {
+    /// Column the cell starts in, counting from the left.
+    #[props(default)]
+    pub col_start: Option<u8>,
+    /// Column the cell starts in, counting from the right.
+    #[props(default)]
+    pub col_from_end: Option<u8>,
+    /// Number of columns the cell covers.
+    #[props(default)]
+    pub col_span: Option<u8>,
+    /// Row the cell starts in, counting from the top.
+    #[props(default)]
+    pub row_start: Option<u8>,
+    /// Row the cell starts in, counting from the bottom.
+    #[props(default)]
+    pub row_from_end: Option<u8>,
+    /// Number of rows the cell covers.
+    #[props(default)]
+    pub row_span: Option<u8>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// A cell of a [`Grid`] or [`FixedGrid`]. Placement values are clamped to
+/// Bulma's `1`–`12` range.
+#[component]
+pub fn Cell(props: CellProps) -> Element {
+    let final_class = classes![
+        "cell",
+        placement_class("is-col-start-", props.col_start, 12),
+        placement_class("is-col-from-end-", props.col_from_end, 12),
+        placement_class("is-col-span-", props.col_span, 12),
+        placement_class("is-row-start-", props.row_start, 12),
+        placement_class("is-row-from-end-", props.row_from_end, 12),
+        placement_class("is-row-span-", props.row_span, 12),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let cell_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{cell_style}",
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
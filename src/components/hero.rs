@@ -1,11 +1,16 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct HeroProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -13,6 +18,8 @@ pub struct HeroProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -21,18 +28,21 @@ pub struct HeroProps {
 
 #[component]
 pub fn Hero(props: HeroProps) -> Element {
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.hero.color);
     let bold = props.bold.unwrap_or(false);
-    let size = props.size.unwrap_or_default();
+    let size = props.size.or(defaults.hero.size).unwrap_or_default();
     
-    let base_classes = vec!["hero"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if bold { Some("is-bold".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "hero",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.background_class(c)),
+        size,
+        bold.then_some("is-bold"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let hero_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -50,6 +60,8 @@ pub struct HeroBodyProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -58,9 +70,7 @@ pub struct HeroBodyProps {
 
 #[component]
 pub fn HeroBody(props: HeroBodyProps) -> Element {
-    let base_classes = vec!["hero-body"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["hero-body", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -77,6 +87,8 @@ pub struct HeroHeadProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -85,9 +97,7 @@ pub struct HeroHeadProps {
 
 #[component]
 pub fn HeroHead(props: HeroHeadProps) -> Element {
-    let base_classes = vec!["hero-head"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["hero-head", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -104,6 +114,8 @@ pub struct HeroFootProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -112,9 +124,7 @@ pub struct HeroFootProps {
 
 #[component]
 pub fn HeroFoot(props: HeroFootProps) -> Element {
-    let base_classes = vec!["hero-foot"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["hero-foot", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
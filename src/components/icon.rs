@@ -1,6 +1,9 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::modifier::BulmaClass;
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct IconProps {
@@ -8,9 +11,17 @@ pub struct IconProps {
     pub size: Option<BulmaSize>,
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -19,16 +30,21 @@ pub struct IconProps {
 
 #[component]
 pub fn Icon(props: IconProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.icon.color);
+    let size = props.size.or(defaults.icon.size).unwrap_or_default();
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["icon"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.color.map(|c| format!("has-text-{}", c.as_str())),
-        props.class.clone(),
+    let final_class = classes![
+        "icon",
+        size,
+        color.map(|c| c.prefixed("has-text-")),
+        color.zip(props.shade).map(|(c, s)| s.text_class(c)),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let icon_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,5 +1,9 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ImageSize {
@@ -58,15 +62,54 @@ impl ImageSize {
     }
 }
 
+impl BulmaClass for ImageSize {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(ImageSize);
+
+named_enum!(ImageSize {
+    Is16x16 => "16x16",
+    Is24x24 => "24x24",
+    Is32x32 => "32x32",
+    Is48x48 => "48x48",
+    Is64x64 => "64x64",
+    Is96x96 => "96x96",
+    Is128x128 => "128x128",
+    IsSquare => "square",
+    Is1by1 => "1by1",
+    Is5by4 => "5by4",
+    Is4by3 => "4by3",
+    Is3by2 => "3by2",
+    Is5by3 => "5by3",
+    Is16by9 => "16by9",
+    Is2by1 => "2by1",
+    Is3by1 => "3by1",
+    Is4by5 => "4by5",
+    Is3by4 => "3by4",
+    Is2by3 => "2by3",
+    Is3by5 => "3by5",
+    Is9by16 => "9by16",
+    Is1by2 => "1by2",
+    Is1by3 => "1by3",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct ImageProps {
     #[props(default)]
     pub size: Option<ImageSize>,
     #[props(default)]
     pub rounded: Option<bool>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -75,16 +118,19 @@ pub struct ImageProps {
 
 #[component]
 pub fn Image(props: ImageProps) -> Element {
-    let rounded = props.rounded.unwrap_or(false);
+    let defaults = use_bulma_defaults();
+    let rounded = props.rounded.or(defaults.image.rounded).unwrap_or(false);
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["image"];
-    let optional_classes = vec![
-        props.size.map(|s| s.as_str().to_string()),
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "image",
+        props.size,
+        rounded.then_some("is-rounded"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let image_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
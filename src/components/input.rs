@@ -1,6 +1,9 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::enums::{impl_name_display, named_enum};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum InputType {
@@ -21,6 +24,15 @@ impl InputType {
     }
 }
 
+named_enum!(InputType {
+    Text => "text",
+    Password => "password",
+    Email => "email",
+    Tel => "tel",
+});
+
+impl_name_display!(InputType);
+
 #[derive(Props, Clone, PartialEq)]
 pub struct InputProps {
     #[props(default = InputType::Text)]
@@ -31,6 +43,10 @@ pub struct InputProps {
     pub placeholder: Option<String>,
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`, applied to the
+    /// border inline since Bulma has no shaded modifiers for it.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -51,9 +67,20 @@ pub struct InputProps {
     pub onfocus: Option<EventHandler<FocusEvent>>,
     #[props(default)]
     pub onblur: Option<EventHandler<FocusEvent>>,
+    /// Keeps the value in this query string parameter, e.g. a search term in
+    /// `?q=…`. Ignored when `value` is set: a controlled input neither reads
+    /// nor writes the URL.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub query_key: Option<String>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -61,26 +88,36 @@ pub struct InputProps {
 
 #[component]
 pub fn Input(props: InputProps) -> Element {
-    let size = props.size.unwrap_or_default();
-    let rounded = props.rounded.unwrap_or(false);
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.input.color);
+    let size = defaults.control_size(&defaults.input, props.size);
+    let rounded = props.rounded.or(defaults.input.rounded).unwrap_or(false);
     let loading = props.loading.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     let readonly = props.readonly.unwrap_or(false);
     let focused = props.focused.unwrap_or(false);
+    let skeleton = props.skeleton.unwrap_or(false);
+    #[cfg(feature = "router")]
+    let mut query = crate::router_helpers::use_query_binding(props.query_key.clone(), String::new());
+    #[cfg(feature = "router")]
+    let value = props.value.clone().or(query.map(|query| query.get()));
+    #[cfg(not(feature = "router"))]
+    let value = props.value.clone();
     
-    let base_classes = vec!["input"];
-    
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        if loading { Some("is-loading".to_string()) } else { None },
-        if focused { Some("is-focused".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "input",
+        color,
+        size,
+        rounded.then_some("is-rounded"),
+        loading.then_some("is-loading"),
+        focused.then_some("is-focused"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let input_style = props.style.as_deref().unwrap_or("");
+    let shade_style = color.zip(props.shade).map(|(c, s)| format!("border-color: {}; ", s.color_value(c)));
+    let input_style = format!("{}{}", shade_style.unwrap_or_default(), props.style.as_deref().unwrap_or(""));
 
     rsx! {
         input {
@@ -88,11 +125,15 @@ pub fn Input(props: InputProps) -> Element {
             class: "{final_class}",
             style: "{input_style}",
             id: props.id.clone(),
-            value: props.value.as_deref().unwrap_or(""),
+            value: value.as_deref().unwrap_or(""),
             placeholder: props.placeholder.as_deref().unwrap_or(""),
             disabled: disabled,
             readonly: readonly,
             oninput: move |evt| {
+                #[cfg(feature = "router")]
+                if props.value.is_none() && let Some(query) = query.as_mut() {
+                    query.set(evt.value());
+                }
                 if let Some(handler) = &props.oninput {
                     handler.call(evt);
                 }
@@ -114,4 +155,4 @@ pub fn Input(props: InputProps) -> Element {
             }
         }
     }
-}
\ No newline at end of file
+}
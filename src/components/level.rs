@@ -1,5 +1,7 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LevelProps {
@@ -8,6 +10,8 @@ pub struct LevelProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -18,13 +22,13 @@ pub struct LevelProps {
 pub fn Level(props: LevelProps) -> Element {
     let mobile = props.mobile.unwrap_or(false);
     
-    let base_classes = vec!["level"];
-    let optional_classes = vec![
-        if mobile { Some("is-mobile".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "level",
+        mobile.then_some("is-mobile"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let level_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -42,6 +46,8 @@ pub struct LevelLeftProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -50,9 +56,7 @@ pub struct LevelLeftProps {
 
 #[component]
 pub fn LevelLeft(props: LevelLeftProps) -> Element {
-    let base_classes = vec!["level-left"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["level-left", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -69,6 +73,8 @@ pub struct LevelRightProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -77,9 +83,7 @@ pub struct LevelRightProps {
 
 #[component]
 pub fn LevelRight(props: LevelRightProps) -> Element {
-    let base_classes = vec!["level-right"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["level-right", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -93,9 +97,19 @@ pub fn LevelRight(props: LevelRightProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct LevelItemProps {
+    #[props(default)]
+    pub href: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    /// If present, use router navigation instead of href
+    #[cfg(feature = "router")]
+    #[props(default, into)]
+    pub to: crate::router_helpers::MaybeNav,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -104,16 +118,18 @@ pub struct LevelItemProps {
 
 #[component]
 pub fn LevelItem(props: LevelItemProps) -> Element {
-    let base_classes = vec!["level-item"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
-    let style = props.style.as_deref().unwrap_or("");
+    let final_class = classes!["level-item", &props.helpers, &props.class];
 
-    rsx! {
-        div {
-            class: "{final_class}",
-            style: "{style}",
-            {props.children}
-        }
-    }
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Div,
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
@@ -0,0 +1,203 @@
+//! The link rendering shared by every link-capable component.
+//!
+//! A [`Linkable`] renders a router [`Link`] when given a `to` target (with the
+//! `router` feature), an `a` when given an `href`, and its
+//! [`LinkFallback`] element otherwise. Components such as `MenuItem`,
+//! `NavbarItem` or `Tag` build their classes and hand them to a `Linkable`
+//! instead of branching on the destination themselves; extension components
+//! can do the same:
+//!
+//! ```rust,ignore
+//! #[component]
+//! fn Chip(#[props(into)] to: MaybeNav, children: Element) -> Element {
+//!     rsx! {
+//!         Linkable { class: "chip", to, fallback: LinkFallback::Span, {children} }
+//!     }
+//! }
+//! ```
+
+use dioxus::prelude::*;
+use crate::enums::{impl_name_display, named_enum};
+
+/// The element a [`Linkable`] renders when it has no destination, or is
+/// disabled.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum LinkFallback {
+    /// An `a` without `href`.
+    #[default]
+    Anchor,
+    /// A `button`, disabled along with the `Linkable`.
+    Button,
+    Div,
+    Paragraph,
+    Span,
+}
+
+named_enum!(LinkFallback {
+    Anchor => "anchor",
+    Button => "button",
+    Div => "div",
+    Paragraph => "paragraph",
+    Span => "span",
+});
+
+impl_name_display!(LinkFallback);
+
+#[derive(Props, Clone, PartialEq)]
+pub struct LinkableProps {
+    #[props(default)]
+    pub href: Option<String>,
+    /// If present, use router navigation instead of href
+    #[cfg(feature = "router")]
+    #[props(default, into)]
+    pub to: crate::router_helpers::MaybeNav,
+    #[props(default)]
+    pub fallback: LinkFallback,
+    /// Renders the fallback element and ignores clicks.
+    #[props(default)]
+    pub disabled: bool,
+    /// Value of `aria-current`, e.g. `"page"`.
+    #[props(default)]
+    pub aria_current: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+impl Default for LinkableProps {
+    fn default() -> Self {
+        Self {
+            href: None,
+            #[cfg(feature = "router")]
+            to: Default::default(),
+            fallback: LinkFallback::default(),
+            disabled: false,
+            aria_current: None,
+            onclick: None,
+            id: None,
+            class: None,
+            style: None,
+            children: VNode::empty(),
+        }
+    }
+}
+
+/// Renders a router `Link`, an `a` or a fallback element depending on the
+/// destination it is given.
+///
+/// `Linkable` uses no hooks, so components call it directly with a
+/// [`LinkableProps`] literal, which lets them forward their `cfg`-gated `to`
+/// prop:
+///
+/// ```rust,ignore
+/// Linkable(LinkableProps {
+///     href: props.href,
+///     #[cfg(feature = "router")]
+///     to: props.to,
+///     class: Some(final_class),
+///     children: props.children,
+///     ..Default::default()
+/// })
+/// ```
+#[component]
+pub fn Linkable(props: LinkableProps) -> Element {
+    let disabled = props.disabled;
+    let onclick = move |evt: MouseEvent| {
+        if !disabled && let Some(handler) = &props.onclick {
+            handler.call(evt);
+        }
+    };
+
+    #[cfg(feature = "router")]
+    if !disabled && let Some(nav_target) = props.to.0 {
+        // `Link` already marks a link to the exact current route with
+        // `aria-current="page"`; don't render the attribute twice.
+        let marked_by_link = matches!(&nav_target, NavigationTarget::Internal(url)
+            if dioxus::router::try_router().is_some_and(|router| router.full_route_string() == *url));
+        return rsx! {
+            Link {
+                to: nav_target,
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                "aria-current": props.aria_current.filter(|_| !marked_by_link),
+                onclick,
+                {props.children}
+            }
+        };
+    }
+
+    if !disabled && let Some(href) = props.href {
+        return rsx! {
+            a {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                href: "{href}",
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        };
+    }
+
+    match props.fallback {
+        LinkFallback::Anchor => rsx! {
+            a {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        },
+        LinkFallback::Button => rsx! {
+            button {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                disabled,
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        },
+        LinkFallback::Div => rsx! {
+            div {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        },
+        LinkFallback::Paragraph => rsx! {
+            p {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        },
+        LinkFallback::Span => rsx! {
+            span {
+                class: props.class,
+                style: props.style,
+                id: props.id,
+                "aria-current": props.aria_current,
+                onclick,
+                {props.children}
+            }
+        },
+    }
+}
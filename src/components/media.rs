@@ -1,11 +1,17 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct MediaProps {
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -14,9 +20,13 @@ pub struct MediaProps {
 
 #[component]
 pub fn Media(props: MediaProps) -> Element {
-    let base_classes = vec!["media"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let skeleton = props.skeleton.unwrap_or(false);
+    let final_class = classes![
+        "media",
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
+    ];
     let media_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -34,6 +44,8 @@ pub struct MediaLeftProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -42,9 +54,7 @@ pub struct MediaLeftProps {
 
 #[component]
 pub fn MediaLeft(props: MediaLeftProps) -> Element {
-    let base_classes = vec!["media-left"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["media-left", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -61,6 +71,8 @@ pub struct MediaRightProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -69,9 +81,7 @@ pub struct MediaRightProps {
 
 #[component]
 pub fn MediaRight(props: MediaRightProps) -> Element {
-    let base_classes = vec!["media-right"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["media-right", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -88,6 +98,8 @@ pub struct MediaContentProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -96,9 +108,7 @@ pub struct MediaContentProps {
 
 #[component]
 pub fn MediaContent(props: MediaContentProps) -> Element {
-    let base_classes = vec!["media-content"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["media-content", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,4 +1,6 @@
-use crate::utils::build_class;
+use crate::components::{Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
 
 #[derive(Props, Clone, PartialEq)]
@@ -6,6 +8,8 @@ pub struct MenuProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -14,9 +18,7 @@ pub struct MenuProps {
 
 #[component]
 pub fn Menu(props: MenuProps) -> Element {
-    let base_classes = vec!["menu"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["menu", &props.helpers, &props.class];
     let menu_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -34,6 +36,8 @@ pub struct MenuLabelProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -42,9 +46,7 @@ pub struct MenuLabelProps {
 
 #[component]
 pub fn MenuLabel(props: MenuLabelProps) -> Element {
-    let base_classes = vec!["menu-label"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["menu-label", &props.helpers, &props.class];
     let label_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -62,6 +64,8 @@ pub struct MenuListProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -70,9 +74,7 @@ pub struct MenuListProps {
 
 #[component]
 pub fn MenuList(props: MenuListProps) -> Element {
-    let base_classes = vec!["menu-list"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["menu-list", &props.helpers, &props.class];
     let list_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -97,9 +99,15 @@ pub struct MenuItemProps {
     #[cfg(feature = "router")]
     #[props(default, into)]
     pub to: crate::router_helpers::MaybeNav,
+    /// Derives `active` from the current route when `active` is unset.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub active_match: Option<crate::router_helpers::ActiveMatch>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -108,65 +116,31 @@ pub struct MenuItemProps {
 
 #[component]
 pub fn MenuItem(props: MenuItemProps) -> Element {
+    #[cfg(feature = "router")]
+    let active = crate::router_helpers::resolve_active(props.active, props.active_match.as_ref(), &props.to);
+    #[cfg(not(feature = "router"))]
     let active = props.active.unwrap_or(false);
     
-    let base_classes = vec![];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let item_style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            li {
-                Link {
-                    to: nav_target,
-                    class: "{final_class}",
-                    style: "{item_style}",
-                    id: props.id.clone(),
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
-            }
-        };
-    }
-
     rsx! {
         li {
-            if let Some(href) = props.href {
-                a {
-                    class: "{final_class}",
-                    style: "{item_style}",
-                    id: props.id.clone(),
-                    href: "{href}",
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
-            } else {
-                a {
-                    class: "{final_class}",
-                    style: "{item_style}",
-                    id: props.id.clone(),
-                    onclick: move |evt| {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    },
-                    {props.children}
-                }
-            }
+            {Linkable(LinkableProps {
+                href: props.href,
+                #[cfg(feature = "router")]
+                to: props.to,
+                aria_current: active.then(|| "page".to_string()),
+                onclick: props.onclick,
+                id: props.id,
+                class: Some(final_class),
+                style: props.style,
+                children: props.children,
+                ..Default::default()
+            })}
         }
     }
 }
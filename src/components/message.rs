@@ -1,16 +1,23 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct MessageProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -19,16 +26,19 @@ pub struct MessageProps {
 
 #[component]
 pub fn Message(props: MessageProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.message.color);
+    let size = props.size.or(defaults.message.size).unwrap_or_default();
     
-    let base_classes = vec!["message"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "message",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.background_class(c)),
+        size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let message_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -50,6 +60,8 @@ pub struct MessageHeaderProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -60,9 +72,7 @@ pub struct MessageHeaderProps {
 pub fn MessageHeader(props: MessageHeaderProps) -> Element {
     let closable = props.closable.unwrap_or(false);
     
-    let base_classes = vec!["message-header"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["message-header", &props.helpers, &props.class];
     let header_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -91,6 +101,8 @@ pub struct MessageBodyProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -99,9 +111,7 @@ pub struct MessageBodyProps {
 
 #[component]
 pub fn MessageBody(props: MessageBodyProps) -> Element {
-    let base_classes = vec!["message-body"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["message-body", &props.helpers, &props.class];
     let body_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,7 +1,9 @@
 // Layout Components
 pub mod container;
 pub mod columns;
+pub mod grid;
 pub mod section;
+pub mod footer;
 pub mod hero;
 pub mod level;
 pub mod media;
@@ -17,6 +19,7 @@ pub mod icon;
 pub mod image;
 pub mod notification;
 pub mod progress;
+pub mod skeleton;
 pub mod table;
 pub mod tag;
 pub mod title;
@@ -34,7 +37,9 @@ pub mod file;
 // Components
 pub mod breadcrumb;
 pub mod card;
+pub mod data_table;
 pub mod dropdown;
+pub mod linkable;
 pub mod menu;
 pub mod message;
 pub mod modal;
@@ -48,7 +53,9 @@ pub mod tabs;
 // Layout Components
 pub use container::*;
 pub use columns::*;
+pub use grid::*;
 pub use section::*;
+pub use footer::*;
 pub use hero::*;
 pub use level::*;
 pub use media::*;
@@ -69,6 +76,7 @@ pub use icon::*;
 pub use image::*;
 pub use notification::*;
 pub use progress::*;
+pub use skeleton::*;
 pub use table::*;
 pub use tag::*;
 pub use title::*;
@@ -86,7 +94,9 @@ pub use file::*;
 // Components
 pub use breadcrumb::*;
 pub use card::*;
+pub use data_table::*;
 pub use dropdown::*;
+pub use linkable::*;
 pub use menu::*;
 pub use message::*;
 pub use modal::*;
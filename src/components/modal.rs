@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ModalProps {
@@ -10,6 +11,8 @@ pub struct ModalProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -18,13 +21,13 @@ pub struct ModalProps {
 
 #[component]
 pub fn Modal(props: ModalProps) -> Element {
-    let base_classes = vec!["modal"];
-    let optional_classes = vec![
-        if props.active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "modal",
+        props.active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let modal_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -50,6 +53,8 @@ pub struct ModalCardProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -58,9 +63,7 @@ pub struct ModalCardProps {
 
 #[component]
 pub fn ModalCard(props: ModalCardProps) -> Element {
-    let base_classes = vec!["modal-card"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["modal-card", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -79,6 +82,8 @@ pub struct ModalCardHeadProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -87,9 +92,7 @@ pub struct ModalCardHeadProps {
 
 #[component]
 pub fn ModalCardHead(props: ModalCardHeadProps) -> Element {
-    let base_classes = vec!["modal-card-head"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["modal-card-head", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -115,6 +118,8 @@ pub struct ModalCardBodyProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -123,9 +128,7 @@ pub struct ModalCardBodyProps {
 
 #[component]
 pub fn ModalCardBody(props: ModalCardBodyProps) -> Element {
-    let base_classes = vec!["modal-card-body"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["modal-card-body", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -142,6 +145,8 @@ pub struct ModalCardFootProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -150,9 +155,7 @@ pub struct ModalCardFootProps {
 
 #[component]
 pub fn ModalCardFoot(props: ModalCardFootProps) -> Element {
-    let base_classes = vec!["modal-card-foot"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["modal-card-foot", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
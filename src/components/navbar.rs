@@ -1,11 +1,74 @@
 use dioxus::prelude::*;
-use crate::theme::BulmaColor;
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, ColorShade};
+use crate::components::{DropdownTrigger, LinkFallback, Linkable, LinkableProps};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Source of the default ids linking a [`NavbarBurger`] to its
+/// [`NavbarMenu`], and of the ids of click-to-open [`NavbarDropdown`]s.
+static NEXT_NAVBAR_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// Handle to the mobile menu state of the nearest [`Navbar`].
+///
+/// [`NavbarBurger`] toggles it and [`NavbarMenu`] shows itself while it is
+/// open; custom toggles can reach it through [`use_navbar`]. The handle is
+/// `Copy`, so it can be moved into event handlers freely.
+#[derive(Clone, Copy, PartialEq)]
+pub struct NavbarContext {
+    open: Signal<bool>,
+    menu_id: CopyValue<String>,
+    auto_close: CopyValue<bool>,
+}
+
+impl NavbarContext {
+    /// Returns `true` while the mobile menu is open, subscribing the caller
+    /// to changes.
+    pub fn is_open(&self) -> bool {
+        (self.open)()
+    }
+
+    pub fn set_open(&mut self, open: bool) {
+        self.open.set(open);
+    }
+
+    pub fn toggle(&mut self) {
+        let open = !*self.open.peek();
+        self.open.set(open);
+    }
+
+    pub fn close(&mut self) {
+        if *self.open.peek() {
+            self.open.set(false);
+        }
+    }
+
+    /// The default id of the navbar's [`NavbarMenu`], referenced by the
+    /// burger's `aria-controls`.
+    pub fn menu_id(&self) -> String {
+        self.menu_id.cloned()
+    }
+
+    /// Returns the underlying signal, for use with `use_memo` and friends.
+    pub fn signal(&self) -> Signal<bool> {
+        self.open
+    }
+}
+
+/// Returns the mobile menu state of the enclosing [`Navbar`], or `None`
+/// outside of one.
+pub fn use_navbar() -> Option<NavbarContext> {
+    try_use_context()
+}
 
 #[derive(Props, Clone, PartialEq)]
 pub struct NavbarProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub transparent: Option<bool>,
     #[props(default)]
@@ -14,9 +77,15 @@ pub struct NavbarProps {
     pub fixed_bottom: Option<bool>,
     #[props(default)]
     pub spaced: Option<bool>,
+    /// Closes the mobile menu when a [`NavbarItem`] is clicked and, with the
+    /// `router` feature, when the route changes.
+    #[props(default)]
+    pub auto_close: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -25,22 +94,48 @@ pub struct NavbarProps {
 
 #[component]
 pub fn Navbar(props: NavbarProps) -> Element {
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.navbar.color);
     let transparent = props.transparent.unwrap_or(false);
     let fixed_top = props.fixed_top.unwrap_or(false);
     let fixed_bottom = props.fixed_bottom.unwrap_or(false);
     let spaced = props.spaced.unwrap_or(false);
+    let auto_close = props.auto_close.unwrap_or(false);
+
+    let open = use_signal(|| false);
+    let mut context = use_context_provider(|| NavbarContext {
+        open,
+        menu_id: CopyValue::new(format!("navbar-menu-{}", NEXT_NAVBAR_ID.fetch_add(1, Ordering::Relaxed))),
+        auto_close: CopyValue::new(auto_close),
+    });
+    context.auto_close.set(auto_close);
+
+    #[cfg(feature = "router")]
+    {
+        let router = dioxus::router::try_router();
+        use_effect(move || {
+            if let Some(router) = router {
+                // Subscribes the effect to route changes.
+                router.full_route_string();
+                if *context.auto_close.peek() {
+                    context.close();
+                }
+            }
+        });
+    }
     
-    let base_classes = vec!["navbar"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if transparent { Some("is-transparent".to_string()) } else { None },
-        if fixed_top { Some("is-fixed-top".to_string()) } else { None },
-        if fixed_bottom { Some("is-fixed-bottom".to_string()) } else { None },
-        if spaced { Some("is-spaced".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "navbar",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.background_class(c)),
+        transparent.then_some("is-transparent"),
+        fixed_top.then_some("is-fixed-top"),
+        fixed_bottom.then_some("is-fixed-bottom"),
+        spaced.then_some("is-spaced"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let navbar_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -60,6 +155,8 @@ pub struct NavbarBrandProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -68,9 +165,7 @@ pub struct NavbarBrandProps {
 
 #[component]
 pub fn NavbarBrand(props: NavbarBrandProps) -> Element {
-    let base_classes = vec!["navbar-brand"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["navbar-brand", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -84,11 +179,15 @@ pub fn NavbarBrand(props: NavbarBrandProps) -> Element {
 
 #[derive(Props, Clone, PartialEq)]
 pub struct NavbarMenuProps {
-    #[props(default = false)]
-    pub active: bool,
+    /// Shows the menu on touch devices. Follows the navbar's
+    /// [`NavbarBurger`] when unset.
+    #[props(default)]
+    pub active: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -97,19 +196,24 @@ pub struct NavbarMenuProps {
 
 #[component]
 pub fn NavbarMenu(props: NavbarMenuProps) -> Element {
-    let base_classes = vec!["navbar-menu"];
-    let optional_classes = vec![
-        if props.active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let context = use_navbar();
+    let active = props.active.unwrap_or_else(|| context.is_some_and(|c| c.is_open()));
+    let menu_id = props.id.clone().or_else(|| context.map(|c| c.menu_id()));
+
+    let final_class = classes![
+        "navbar-menu",
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
         div {
             class: "{final_class}",
             style: "{style}",
+            id: menu_id,
             {props.children}
         }
     }
@@ -120,6 +224,8 @@ pub struct NavbarStartProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -128,9 +234,7 @@ pub struct NavbarStartProps {
 
 #[component]
 pub fn NavbarStart(props: NavbarStartProps) -> Element {
-    let base_classes = vec!["navbar-start"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["navbar-start", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -147,6 +251,8 @@ pub struct NavbarEndProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -155,9 +261,7 @@ pub struct NavbarEndProps {
 
 #[component]
 pub fn NavbarEnd(props: NavbarEndProps) -> Element {
-    let base_classes = vec!["navbar-end"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["navbar-end", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -179,9 +283,19 @@ pub struct NavbarItemProps {
     pub href: Option<String>,
     #[props(default)]
     pub onclick: Option<EventHandler<MouseEvent>>,
+    /// If present, use router navigation instead of href
+    #[cfg(feature = "router")]
+    #[props(default, into)]
+    pub to: crate::router_helpers::MaybeNav,
+    /// Derives `active` from the current route when `active` is unset.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub active_match: Option<crate::router_helpers::ActiveMatch>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -190,41 +304,246 @@ pub struct NavbarItemProps {
 
 #[component]
 pub fn NavbarItem(props: NavbarItemProps) -> Element {
+    let context = use_navbar();
+    #[cfg(feature = "router")]
+    let active = crate::router_helpers::resolve_active(props.active, props.active_match.as_ref(), &props.to);
+    #[cfg(not(feature = "router"))]
     let active = props.active.unwrap_or(false);
     let hoverable = props.hoverable.unwrap_or(false);
     
-    let base_classes = vec!["navbar-item"];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        if hoverable { Some("is-hoverable".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "navbar-item",
+        active.then_some("is-active"),
+        hoverable.then_some("is-hoverable"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
+    let onclick = props.onclick;
+
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Div,
+        aria_current: active.then(|| "page".to_string()),
+        onclick: Some(EventHandler::new(move |evt| {
+            if let Some(mut context) = context
+                && *context.auto_close.peek()
+            {
+                context.close();
+            }
+            if let Some(handler) = &onclick {
+                handler.call(evt);
+            }
+        })),
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarBurgerProps {
+    /// Id of the menu the burger controls. Defaults to the id the navbar
+    /// gives its [`NavbarMenu`]; set it when the menu has an `id` of its own.
+    #[props(default)]
+    pub target: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+}
+
+/// The hamburger button opening the [`NavbarMenu`] on touch devices. Place it
+/// at the end of the [`NavbarBrand`].
+#[component]
+pub fn NavbarBurger(props: NavbarBurgerProps) -> Element {
+    let context = use_navbar();
+    let open = context.is_some_and(|c| c.is_open());
+    let target = props.target.clone().or_else(|| context.map(|c| c.menu_id()));
+
+    let final_class = classes![
+        "navbar-burger",
+        open.then_some("is-active"),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        a {
+            class: "{final_class}",
+            style: "{style}",
+            id: props.id.clone(),
+            role: "button",
+            "aria-label": "menu",
+            "aria-expanded": "{open}",
+            "aria-controls": target.clone(),
+            "data-target": target,
+            onclick: move |evt| {
+                if let Some(mut context) = context {
+                    context.toggle();
+                }
+                if let Some(handler) = &props.onclick {
+                    handler.call(evt);
+                }
+            },
+            span { "aria-hidden": "true" }
+            span { "aria-hidden": "true" }
+            span { "aria-hidden": "true" }
+            span { "aria-hidden": "true" }
+        }
+    }
+}
+
+/// Reports clicks outside of the element whose id is sent first, until a
+/// second message arrives or the element leaves the document.
+const OUTSIDE_CLICK_JS: &str = r#"
+    const id = await dioxus.recv();
+    const onClick = (event) => {
+        const element = document.getElementById(id);
+        if (!element) {
+            document.removeEventListener("click", onClick);
+        } else if (!element.contains(event.target)) {
+            dioxus.send(true);
+        }
+    };
+    document.addEventListener("click", onClick);
+    await dioxus.recv();
+    document.removeEventListener("click", onClick);
+"#;
+
+/// Stops an [`OUTSIDE_CLICK_JS`] listener when dropped.
+struct OutsideClickListener(document::Eval);
+
+impl Drop for OutsideClickListener {
+    fn drop(&mut self) {
+        let _ = self.0.send(());
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarDropdownProps {
+    /// Content of the [`NavbarLink`] opening the dropdown.
+    pub label: Element,
+    /// `Hover` (the default) opens the dropdown on hover; `Click` toggles it
+    /// when the link is clicked and closes it on clicks outside of it.
+    #[props(default)]
+    pub trigger: Option<DropdownTrigger>,
+    /// Keeps the dropdown open regardless of `trigger`.
+    #[props(default)]
+    pub active: Option<bool>,
+    /// Aligns the dropdown to the right of its link.
+    #[props(default)]
+    pub right: Option<bool>,
+    /// Draws the dropdown as a box, for transparent navbars.
+    #[props(default)]
+    pub boxed: Option<bool>,
+    /// Opens the dropdown above the link, for navbars fixed to the bottom.
+    #[props(default)]
+    pub up: Option<bool>,
+    /// Hides the link's arrow.
+    #[props(default)]
+    pub arrowless: Option<bool>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// A navbar item with a dropdown menu of [`NavbarItem`]s and
+/// [`NavbarDivider`]s.
+///
+/// ```rust,ignore
+/// NavbarDropdown { label: rsx! { "More" }, trigger: DropdownTrigger::Click, right: true,
+///     NavbarItem { href: "/about", "About" }
+///     NavbarDivider {}
+///     NavbarItem { href: "/contact", "Contact" }
+/// }
+/// ```
+#[component]
+pub fn NavbarDropdown(props: NavbarDropdownProps) -> Element {
+    let click = props.trigger == Some(DropdownTrigger::Click);
+    let right = props.right.unwrap_or(false);
+    let boxed = props.boxed.unwrap_or(false);
+    let up = props.up.unwrap_or(false);
+
+    let mut open = use_signal(|| false);
+    let dropdown_id = use_hook(|| format!("navbar-dropdown-{}", NEXT_NAVBAR_ID.fetch_add(1, Ordering::Relaxed)));
+    let element_id = props.id.clone().unwrap_or_else(|| dropdown_id.clone());
+
+    // Restarted whenever the trigger or id changes; dropping the running
+    // task (on restart or unmount) removes the previous listener.
+    let watched_id = element_id.clone();
+    use_resource(use_reactive!(|(click, watched_id)| async move {
+        if !click {
+            return;
+        }
+        let mut listener = OutsideClickListener(document::eval(OUTSIDE_CLICK_JS));
+        if listener.0.send(watched_id).is_err() {
+            return;
+        }
+        while listener.0.recv::<bool>().await.is_ok() {
+            if *open.peek() {
+                open.set(false);
+            }
+        }
+    }));
+
+    let active = props.active.unwrap_or(click && open());
+
+    let final_class = classes![
+        "navbar-item",
+        "has-dropdown",
+        (!click).then_some("is-hoverable"),
+        active.then_some("is-active"),
+        up.then_some("has-dropdown-up"),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let dropdown_class = classes![
+        "navbar-dropdown",
+        right.then_some("is-right"),
+        boxed.then_some("is-boxed"),
+    ];
+
     let style = props.style.as_deref().unwrap_or("");
 
-    if let Some(href) = props.href {
-        rsx! {
-            a {
-                class: "{final_class}",
-                style: "{style}",
-                href: "{href}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{style}",
+            id: element_id,
+            NavbarLink {
+                arrowless: props.arrowless,
+                onclick: move |_| {
+                    if click {
+                        open.toggle();
                     }
                 },
-                {props.children}
+                {props.label}
             }
-        }
-    } else {
-        rsx! {
             div {
-                class: "{final_class}",
-                style: "{style}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
+                class: "{dropdown_class}",
+                onclick: move |_| {
+                    if click {
+                        open.set(false);
                     }
                 },
                 {props.children}
@@ -232,3 +551,80 @@ pub fn NavbarItem(props: NavbarItemProps) -> Element {
         }
     }
 }
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarLinkProps {
+    /// Hides the dropdown arrow.
+    #[props(default)]
+    pub arrowless: Option<bool>,
+    #[props(default)]
+    pub href: Option<String>,
+    #[props(default)]
+    pub onclick: Option<EventHandler<MouseEvent>>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// The link opening a navbar dropdown, drawn with an arrow.
+#[component]
+pub fn NavbarLink(props: NavbarLinkProps) -> Element {
+    let arrowless = props.arrowless.unwrap_or(false);
+
+    let final_class = classes![
+        "navbar-link",
+        arrowless.then_some("is-arrowless"),
+        &props.helpers,
+        &props.class,
+    ];
+
+    let style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        a {
+            class: "{final_class}",
+            style: "{style}",
+            id: props.id.clone(),
+            href: props.href.clone(),
+            onclick: move |evt| {
+                if let Some(handler) = &props.onclick {
+                    handler.call(evt);
+                }
+            },
+            {props.children}
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct NavbarDividerProps {
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+}
+
+/// A horizontal rule between the items of a [`NavbarDropdown`].
+#[component]
+pub fn NavbarDivider(props: NavbarDividerProps) -> Element {
+    let final_class = classes!["navbar-divider", &props.helpers, &props.class];
+    let style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        hr {
+            class: "{final_class}",
+            style: "{style}",
+            id: props.id.clone(),
+        }
+    }
+}
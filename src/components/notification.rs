@@ -1,11 +1,17 @@
 use dioxus::prelude::*;
-use crate::theme::BulmaColor;
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct NotificationProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
+    /// Shorthand for `shade: ColorShade::Light`.
     #[props(default)]
     pub light: Option<bool>,
     #[props(default)]
@@ -15,6 +21,8 @@ pub struct NotificationProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -23,19 +31,20 @@ pub struct NotificationProps {
 
 #[component]
 pub fn Notification(props: NotificationProps) -> Element {
-    let color = props.color.unwrap_or(BulmaColor::Primary);
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.notification.color).unwrap_or(BulmaColor::Primary);
     let light = props.light.unwrap_or(false);
     let dismissible = props.dismissible.unwrap_or(false);
     
-    let base_classes = vec!["notification"];
-    
-    let optional_classes = vec![
-        Some(format!("is-{}", color.as_str())),
-        if light { Some("is-light".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "notification",
+        color,
+        props.shade.map(|s| s.fill_class(color)),
+        light.then_some("is-light"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let notification_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -56,4 +65,4 @@ pub fn Notification(props: NotificationProps) -> Element {
             {props.children}
         }
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,11 @@
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::components::{Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum PaginationAlignment {
@@ -19,6 +24,20 @@ impl PaginationAlignment {
     }
 }
 
+impl BulmaClass for PaginationAlignment {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(PaginationAlignment);
+
+named_enum!(PaginationAlignment {
+    Left => "left",
+    Centered => "centered",
+    Right => "right",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct PaginationProps {
     #[props(default)]
@@ -30,6 +49,8 @@ pub struct PaginationProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -38,19 +59,20 @@ pub struct PaginationProps {
 
 #[component]
 pub fn Pagination(props: PaginationProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = defaults.control_size(&defaults.pagination, props.size);
     let alignment = props.alignment.unwrap_or(PaginationAlignment::Left);
-    let rounded = props.rounded.unwrap_or(false);
+    let rounded = props.rounded.or(defaults.pagination.rounded).unwrap_or(false);
     
-    let base_classes = vec!["pagination"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if alignment != PaginationAlignment::Left { Some(alignment.as_str().to_string()) } else { None },
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "pagination",
+        size,
+        alignment,
+        rounded.then_some("is-rounded"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let pagination_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -80,6 +102,8 @@ pub struct PaginationPreviousProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -90,45 +114,20 @@ pub struct PaginationPreviousProps {
 pub fn PaginationPrevious(props: PaginationPreviousProps) -> Element {
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["pagination-previous"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
-    let style = props.style.as_deref().unwrap_or("");
+    let final_class = classes!["pagination-previous", &props.helpers, &props.class];
 
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            Link {
-                to: nav_target,
-                class: "{final_class}",
-                style: "{style}",
-                onclick: move |evt| {
-                    if !disabled {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    }
-                },
-                {props.children}
-            }
-        };
-    }
-
-    rsx! {
-        a {
-            class: "{final_class}",
-            style: "{style}",
-            href: if disabled { None } else { props.href.as_deref() },
-            onclick: move |evt| {
-                if !disabled {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                }
-            },
-            {props.children}
-        }
-    }
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        disabled,
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -146,6 +145,8 @@ pub struct PaginationNextProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -156,45 +157,20 @@ pub struct PaginationNextProps {
 pub fn PaginationNext(props: PaginationNextProps) -> Element {
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["pagination-next"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
-    let style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            Link {
-                to: nav_target,
-                class: "{final_class}",
-                style: "{style}",
-                onclick: move |evt| {
-                    if !disabled {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    }
-                },
-                {props.children}
-            }
-        };
-    }
+    let final_class = classes!["pagination-next", &props.helpers, &props.class];
 
-    rsx! {
-        a {
-            class: "{final_class}",
-            style: "{style}",
-            href: if disabled { None } else { props.href.as_deref() },
-            onclick: move |evt| {
-                if !disabled {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                }
-            },
-            {props.children}
-        }
-    }
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        disabled,
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -202,6 +178,8 @@ pub struct PaginationListProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -210,9 +188,7 @@ pub struct PaginationListProps {
 
 #[component]
 pub fn PaginationList(props: PaginationListProps) -> Element {
-    let base_classes = vec!["pagination-list"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["pagination-list", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -241,6 +217,8 @@ pub struct PaginationLinkProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -252,53 +230,28 @@ pub fn PaginationLink(props: PaginationLinkProps) -> Element {
     let current = props.current.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["pagination-link"];
-    let optional_classes = vec![
-        if current { Some("is-current".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "pagination-link",
+        current.then_some("is-current"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            li {
-                Link {
-                    to: nav_target,
-                    class: "{final_class}",
-                    style: "{style}",
-                    "aria-current": if current { "page" } else { "" },
-                    onclick: move |evt| {
-                        if !disabled {
-                            if let Some(handler) = &props.onclick {
-                                handler.call(evt);
-                            }
-                        }
-                    },
-                    {props.children}
-                }
-            }
-        };
-    }
-
     rsx! {
         li {
-            a {
-                class: "{final_class}",
-                style: "{style}",
-                href: if disabled { None } else { props.href.as_deref() },
-                "aria-current": if current { "page" } else { "" },
-                onclick: move |evt| {
-                    if !disabled {
-                        if let Some(handler) = &props.onclick {
-                            handler.call(evt);
-                        }
-                    }
-                },
-                {props.children}
-            }
+            {Linkable(LinkableProps {
+                href: props.href,
+                #[cfg(feature = "router")]
+                to: props.to,
+                disabled,
+                aria_current: current.then(|| "page".to_string()),
+                onclick: props.onclick,
+                id: props.id,
+                class: Some(final_class),
+                style: props.style,
+                children: props.children,
+                ..Default::default()
+            })}
         }
     }
 }
@@ -308,6 +261,8 @@ pub struct PaginationEllipsisProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -315,9 +270,7 @@ pub struct PaginationEllipsisProps {
 
 #[component]
 pub fn PaginationEllipsis(props: PaginationEllipsisProps) -> Element {
-    let base_classes = vec!["pagination-ellipsis"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["pagination-ellipsis", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -330,3 +283,312 @@ pub fn PaginationEllipsis(props: PaginationEllipsisProps) -> Element {
         }
     }
 }
+
+/// One entry of a page window computed by [`page_window`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageItem {
+    Page(usize),
+    Ellipsis,
+}
+
+/// The pages to show for `page` out of `total_pages`: the first and last
+/// page, `siblings` pages on each side of the current one, and ellipses
+/// where pages are left out. An ellipsis never stands in for a single page,
+/// which is shown instead.
+///
+/// ```rust
+/// use dioxus_bulma::components::{page_window, PageItem::*};
+///
+/// assert_eq!(page_window(6, 20, 1), [Page(1), Ellipsis, Page(5), Page(6), Page(7), Ellipsis, Page(20)]);
+/// assert_eq!(page_window(2, 4, 1), [Page(1), Page(2), Page(3), Page(4)]);
+/// ```
+pub fn page_window(page: usize, total_pages: usize, siblings: usize) -> Vec<PageItem> {
+    if total_pages == 0 {
+        return Vec::new();
+    }
+
+    let page = page.clamp(1, total_pages);
+    let start = page.saturating_sub(siblings).max(1);
+    let end = page.saturating_add(siblings).min(total_pages);
+    let mut items = Vec::new();
+
+    if start > 1 {
+        items.push(PageItem::Page(1));
+    }
+    match start {
+        0..=2 => {}
+        3 => items.push(PageItem::Page(2)),
+        _ => items.push(PageItem::Ellipsis),
+    }
+    items.extend((start..=end).map(PageItem::Page));
+    match total_pages - end {
+        0 | 1 => {}
+        2 => items.push(PageItem::Page(total_pages - 1)),
+        _ => items.push(PageItem::Ellipsis),
+    }
+    if end < total_pages {
+        items.push(PageItem::Page(total_pages));
+    }
+
+    items
+}
+
+/// Current page of a paginated list, returned by [`use_pagination`].
+///
+/// Pages are numbered from 1. The page is clamped to the page count on
+/// every read, so it stays valid when the list shrinks. The handle is
+/// `Copy`, so it can be moved into event handlers freely.
+#[derive(Clone, Copy, PartialEq)]
+pub struct PaginationState {
+    page: Signal<usize>,
+    total_items: usize,
+    page_size: usize,
+    #[cfg(feature = "router")]
+    query: Option<crate::router_helpers::QueryState<usize>>,
+}
+
+impl PaginationState {
+    /// Returns the current page, subscribing the caller to changes.
+    pub fn page(&self) -> usize {
+        (self.page)().clamp(1, self.total_pages())
+    }
+
+    /// Moves to `page`, clamped to the existing pages.
+    pub fn set_page(&mut self, page: usize) {
+        let page = page.clamp(1, self.total_pages());
+        #[cfg(feature = "router")]
+        if let Some(query) = self.query.as_mut() {
+            query.set(page);
+            return;
+        }
+        if *self.page.peek() != page {
+            self.page.set(page);
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let page = self.page.peek().clamp(1, self.total_pages());
+        self.set_page(page - 1);
+    }
+
+    pub fn next(&mut self) {
+        let page = self.page.peek().clamp(1, self.total_pages());
+        self.set_page(page + 1);
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.page() > 1
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.page() < self.total_pages()
+    }
+
+    /// Number of pages; an empty list still has one.
+    pub fn total_pages(&self) -> usize {
+        self.total_items.div_ceil(self.page_size).max(1)
+    }
+
+    pub fn total_items(&self) -> usize {
+        self.total_items
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.page_size
+    }
+
+    /// Indices of the current page's items, for slicing the full list.
+    pub fn item_range(&self) -> std::ops::Range<usize> {
+        let start = (self.page() - 1) * self.page_size;
+        start.min(self.total_items)..(start + self.page_size).min(self.total_items)
+    }
+
+    /// The page window around the current page; see [`page_window`].
+    pub fn window(&self, siblings: usize) -> Vec<PageItem> {
+        page_window(self.page(), self.total_pages(), siblings)
+    }
+
+    /// Returns the underlying signal, for use with `use_memo` and friends.
+    pub fn signal(&self) -> Signal<usize> {
+        self.page
+    }
+}
+
+/// Tracks the current page of a list of `total_items` shown `page_size` at a
+/// time, starting on page 1. Pass the result to a [`Paginator`]:
+///
+/// ```rust,ignore
+/// let pagination = use_pagination(devices.len(), 20);
+/// rsx! {
+///     for device in &devices[pagination.item_range()] { DeviceRow { device: device.clone() } }
+///     Paginator { pagination }
+/// }
+/// ```
+pub fn use_pagination(total_items: usize, page_size: usize) -> PaginationState {
+    let page = use_signal(|| 1);
+    PaginationState {
+        page,
+        total_items,
+        page_size: page_size.max(1),
+        #[cfg(feature = "router")]
+        query: None,
+    }
+}
+
+/// [`use_pagination`] with the page kept in the `key` query parameter, so it
+/// survives reloads. Page 1 leaves the parameter out.
+///
+/// ```rust,ignore
+/// // /devices?page=3 opens on the third page.
+/// let pagination = use_query_pagination("page", devices.len(), 20);
+/// ```
+#[cfg(feature = "router")]
+pub fn use_query_pagination(key: impl Into<String>, total_items: usize, page_size: usize) -> PaginationState {
+    let query = crate::router_helpers::use_query_state(key, 1);
+    PaginationState {
+        page: query.signal(),
+        total_items,
+        page_size: page_size.max(1),
+        query: Some(query),
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct PaginatorProps {
+    /// Page state from [`use_pagination`]. The paginator reads the page and
+    /// page count from it and moves it when a page is picked.
+    #[props(default)]
+    pub pagination: Option<PaginationState>,
+    /// Current page, counting from 1. Overrides `pagination`.
+    #[props(default)]
+    pub page: Option<usize>,
+    /// Number of pages. Overrides `pagination`.
+    #[props(default)]
+    pub total_pages: Option<usize>,
+    /// Pages shown on each side of the current one; defaults to 2.
+    #[props(default)]
+    pub siblings: Option<usize>,
+    /// Called with the picked page when it differs from the current one.
+    #[props(default)]
+    pub on_page_change: Option<EventHandler<usize>>,
+    /// Builds each page's `href`, e.g. `move |page| format!("?page={page}")`.
+    #[props(default)]
+    pub href: Option<Callback<usize, String>>,
+    /// Builds each page's router target; used instead of `href` when set.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub to: Option<Callback<usize, NavigationTarget>>,
+    /// Defaults to "Previous".
+    #[props(default)]
+    pub previous_label: Option<String>,
+    /// Defaults to "Next".
+    #[props(default)]
+    pub next_label: Option<String>,
+    #[props(default)]
+    pub size: Option<BulmaSize>,
+    #[props(default)]
+    pub alignment: Option<PaginationAlignment>,
+    #[props(default)]
+    pub rounded: Option<bool>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+}
+
+/// A complete [`Pagination`]: previous and next links, disabled on the first
+/// and last page, and a window of page links with ellipses.
+///
+/// ```rust,ignore
+/// // Self-managed through use_pagination:
+/// Paginator { pagination, on_page_change: move |page| tracing::info!("page {page}") }
+///
+/// // Controlled, with a page route per link:
+/// Paginator {
+///     page,
+///     total_pages: 12,
+///     to: move |page| NavigationTarget::from(Route::Devices { page }),
+/// }
+/// ```
+#[component]
+pub fn Paginator(props: PaginatorProps) -> Element {
+    let mut pagination = props.pagination;
+    let total_pages = props
+        .total_pages
+        .or(pagination.map(|pagination| pagination.total_pages()))
+        .unwrap_or(1)
+        .max(1);
+    let page = props
+        .page
+        .or(pagination.map(|pagination| pagination.page()))
+        .unwrap_or(1)
+        .clamp(1, total_pages);
+    let window = page_window(page, total_pages, props.siblings.unwrap_or(2));
+
+    let on_page_change = props.on_page_change;
+    let href = props.href;
+    #[cfg(feature = "router")]
+    let to = props.to;
+
+    let link = move |target: usize, class: &str, disabled: bool, label: String| {
+        let current = target == page;
+        let final_class = classes![
+            class,
+            (class == "pagination-link" && current).then_some("is-current"),
+            disabled.then_some("is-disabled"),
+        ];
+
+        Linkable(LinkableProps {
+            href: href.map(|href| href.call(target)),
+            #[cfg(feature = "router")]
+            to: crate::router_helpers::MaybeNav(to.map(|to| to.call(target))),
+            disabled,
+            aria_current: (class == "pagination-link" && current).then(|| "page".to_string()),
+            onclick: Some(EventHandler::new(move |_| {
+                if target == page {
+                    return;
+                }
+                if let Some(pagination) = pagination.as_mut() {
+                    pagination.set_page(target);
+                }
+                if let Some(handler) = on_page_change {
+                    handler.call(target);
+                }
+            })),
+            class: Some(final_class),
+            children: rsx! { "{label}" },
+            ..Default::default()
+        })
+    };
+
+    let previous_label = props.previous_label.unwrap_or_else(|| "Previous".to_string());
+    let next_label = props.next_label.unwrap_or_else(|| "Next".to_string());
+
+    rsx! {
+        Pagination {
+            size: props.size,
+            alignment: props.alignment,
+            rounded: props.rounded,
+            id: props.id,
+            helpers: props.helpers,
+            class: props.class,
+            style: props.style,
+            {link(page.saturating_sub(1).max(1), "pagination-previous", page == 1, previous_label)}
+            {link((page + 1).min(total_pages), "pagination-next", page == total_pages, next_label)}
+            PaginationList {
+                for item in window {
+                    match item {
+                        PageItem::Page(target) => rsx! {
+                            li { {link(target, "pagination-link", false, target.to_string())} }
+                        },
+                        PageItem::Ellipsis => rsx! { PaginationEllipsis {} },
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,14 +1,22 @@
-use crate::theme::BulmaColor;
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct PanelProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -17,13 +25,16 @@ pub struct PanelProps {
 
 #[component]
 pub fn Panel(props: PanelProps) -> Element {
-    let base_classes = vec!["panel"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        props.class.clone(),
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.panel.color);
+    let final_class = classes![
+        "panel",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.background_class(c)),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let panel_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -41,6 +52,8 @@ pub struct PanelHeadingProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -49,9 +62,7 @@ pub struct PanelHeadingProps {
 
 #[component]
 pub fn PanelHeading(props: PanelHeadingProps) -> Element {
-    let base_classes = vec!["panel-heading"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["panel-heading", &props.helpers, &props.class];
     let heading_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -69,6 +80,8 @@ pub struct PanelTabsProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -77,9 +90,7 @@ pub struct PanelTabsProps {
 
 #[component]
 pub fn PanelTabs(props: PanelTabsProps) -> Element {
-    let base_classes = vec!["panel-tabs"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["panel-tabs", &props.helpers, &props.class];
     let tabs_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -104,9 +115,15 @@ pub struct PanelBlockProps {
     #[cfg(feature = "router")]
     #[props(default, into)]
     pub to: crate::router_helpers::MaybeNav,
+    /// Derives `active` from the current route when `active` is unset.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub active_match: Option<crate::router_helpers::ActiveMatch>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -115,63 +132,31 @@ pub struct PanelBlockProps {
 
 #[component]
 pub fn PanelBlock(props: PanelBlockProps) -> Element {
+    #[cfg(feature = "router")]
+    let active = crate::router_helpers::resolve_active(props.active, props.active_match.as_ref(), &props.to);
+    #[cfg(not(feature = "router"))]
     let active = props.active.unwrap_or(false);
     
-    let base_classes = vec!["panel-block"];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "panel-block",
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let block_style = props.style.as_deref().unwrap_or("");
-
-    #[cfg(feature = "router")]
-    if let Some(nav_target) = props.to.0 {
-        return rsx! {
-            Link {
-                to: nav_target,
-                class: "{final_class}",
-                style: "{block_style}",
-                id: props.id.clone(),
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        };
-    }
-
-    rsx! {
-        if let Some(href) = props.href {
-            a {
-                class: "{final_class}",
-                style: "{block_style}",
-                id: props.id.clone(),
-                href: "{href}",
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        } else {
-            div {
-                class: "{final_class}",
-                style: "{block_style}",
-                id: props.id.clone(),
-                onclick: move |evt| {
-                    if let Some(handler) = &props.onclick {
-                        handler.call(evt);
-                    }
-                },
-                {props.children}
-            }
-        }
-    }
+    Linkable(LinkableProps {
+        href: props.href,
+        #[cfg(feature = "router")]
+        to: props.to,
+        fallback: LinkFallback::Div,
+        aria_current: active.then(|| "page".to_string()),
+        onclick: props.onclick,
+        id: props.id,
+        class: Some(final_class),
+        style: props.style,
+        children: props.children,
+        ..Default::default()
+    })
 }
 
 #[derive(Props, Clone, PartialEq)]
@@ -179,6 +164,8 @@ pub struct PanelIconProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -187,9 +174,7 @@ pub struct PanelIconProps {
 
 #[component]
 pub fn PanelIcon(props: PanelIconProps) -> Element {
-    let base_classes = vec!["panel-icon"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["panel-icon", &props.helpers, &props.class];
     let icon_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
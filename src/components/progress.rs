@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct ProgressProps {
@@ -10,11 +12,17 @@ pub struct ProgressProps {
     pub max: f32,
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`, applied to the
+    /// bar inline since Bulma has no shaded modifiers for it.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -23,17 +31,22 @@ pub struct ProgressProps {
 
 #[component]
 pub fn Progress(props: ProgressProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.progress.color);
+    let size = props.size.or(defaults.progress.size).unwrap_or_default();
 
-    let base_classes = vec!["progress"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "progress",
+        color,
+        size,
+        &props.helpers,
+        &props.class,
     ];
 
-    let final_class = build_class(&base_classes, &optional_classes);
-    let progress_style = props.style.as_deref().unwrap_or("");
+    let shade_style = color
+        .zip(props.shade)
+        .map(|(c, s)| format!("--bulma-progress-value-background-color: {}; ", s.color_value(c)));
+    let progress_style = format!("{}{}", shade_style.unwrap_or_default(), props.style.as_deref().unwrap_or(""));
     let value_str = props.value.map(|v| v.to_string());
     let max_str = props.max.to_string();
 
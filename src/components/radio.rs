@@ -1,5 +1,6 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct RadioProps {
@@ -14,6 +15,8 @@ pub struct RadioProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -25,9 +28,7 @@ pub fn Radio(props: RadioProps) -> Element {
     let checked = props.checked.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec!["radio"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["radio", &props.helpers, &props.class];
     let radio_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
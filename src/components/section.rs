@@ -1,14 +1,24 @@
 use dioxus::prelude::*;
+use crate::responsive::{Responsive, TextAlignment};
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct SectionProps {
     #[props(default)]
     pub size: Option<BulmaSize>,
+    /// Hides the element, optionally only at some breakpoints.
+    #[props(default, into)]
+    pub hidden: Responsive<bool>,
+    /// Aligns the element's text, optionally per breakpoint.
+    #[props(default, into)]
+    pub text_align: Responsive<TextAlignment>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -17,18 +27,15 @@ pub struct SectionProps {
 
 #[component]
 pub fn Section(props: SectionProps) -> Element {
-    let base_classes = vec!["section"];
-    
-    let optional_classes = vec![
-        props.size.and_then(|s| if s != BulmaSize::Normal { 
-            Some(s.as_class().to_string()) 
-        } else { 
-            None 
-        }),
-        props.class.clone(),
+    let final_class = classes![
+        "section",
+        props.size,
+        props.hidden.to_hidden_class(),
+        &props.text_align,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let section_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct SelectProps {
@@ -8,6 +10,10 @@ pub struct SelectProps {
     pub value: Option<String>,
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`, applied to the
+    /// border of the inner `select` inline since Bulma has no shaded modifiers for it.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -23,6 +29,8 @@ pub struct SelectProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -31,24 +39,27 @@ pub struct SelectProps {
 
 #[component]
 pub fn Select(props: SelectProps) -> Element {
-    let size = props.size.unwrap_or_default();
-    let rounded = props.rounded.unwrap_or(false);
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.select.color);
+    let size = defaults.control_size(&defaults.select, props.size);
+    let rounded = props.rounded.or(defaults.select.rounded).unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     let loading = props.loading.unwrap_or(false);
     let multiple = props.multiple.unwrap_or(false);
     
-    let base_classes = vec!["select"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        if loading { Some("is-loading".to_string()) } else { None },
-        if multiple { Some("is-multiple".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "select",
+        color,
+        size,
+        rounded.then_some("is-rounded"),
+        loading.then_some("is-loading"),
+        multiple.then_some("is-multiple"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let select_style = props.style.as_deref().unwrap_or("");
+    let shade_style = color.zip(props.shade).map(|(c, s)| format!("border-color: {};", s.color_value(c)));
 
     rsx! {
         div {
@@ -56,6 +67,7 @@ pub fn Select(props: SelectProps) -> Element {
             style: "{select_style}",
             id: props.id.clone(),
             select {
+                style: shade_style,
                 value: props.value.as_deref().unwrap_or(""),
                 disabled: disabled,
                 multiple: multiple,
@@ -81,6 +93,8 @@ pub struct OptionProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -92,7 +106,7 @@ pub fn Option(props: OptionProps) -> Element {
     let selected = props.selected.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     let option_style = props.style.as_deref().unwrap_or("");
-    let option_class = props.class.as_deref().unwrap_or("");
+    let option_class = classes![&props.helpers, &props.class];
 
     rsx! {
         option {
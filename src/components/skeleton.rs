@@ -0,0 +1,71 @@
+use dioxus::prelude::*;
+use crate::helpers::Helpers;
+use crate::classes;
+
+/// A Bulma `skeleton-block` — a pulsing placeholder box shown while content
+/// loads. Children are rendered invisibly and only give the block its size;
+/// without children the block takes Bulma's default height.
+///
+/// See <https://bulma.io/documentation/features/skeletons/>.
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonBlockProps {
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+#[component]
+pub fn SkeletonBlock(props: SkeletonBlockProps) -> Element {
+    let final_class = classes!["skeleton-block", &props.helpers, &props.class];
+    let block_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{block_style}",
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
+
+/// A Bulma `skeleton-lines` placeholder standing in for a paragraph of text.
+/// The last line is drawn shorter, like the end of a paragraph.
+#[derive(Props, Clone, PartialEq)]
+pub struct SkeletonLinesProps {
+    /// Number of lines; defaults to 5.
+    #[props(default)]
+    pub lines: Option<usize>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+}
+
+#[component]
+pub fn SkeletonLines(props: SkeletonLinesProps) -> Element {
+    let lines = props.lines.unwrap_or(5);
+    let final_class = classes!["skeleton-lines", &props.helpers, &props.class];
+    let lines_style = props.style.as_deref().unwrap_or("");
+
+    rsx! {
+        div {
+            class: "{final_class}",
+            style: "{lines_style}",
+            id: props.id.clone(),
+            for _ in 0..lines {
+                div {}
+            }
+        }
+    }
+}
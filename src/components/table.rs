@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::{use_bulma_defaults, Density};
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TableProps {
@@ -19,6 +21,8 @@ pub struct TableProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -27,25 +31,26 @@ pub struct TableProps {
 
 #[component]
 pub fn Table(props: TableProps) -> Element {
+    let defaults = use_bulma_defaults();
     let bordered = props.bordered.unwrap_or(false);
     let striped = props.striped.unwrap_or(false);
-    let narrow = props.narrow.unwrap_or(false);
+    let narrow = props.narrow.unwrap_or(defaults.density == Density::Compact);
     let hoverable = props.hoverable.unwrap_or(false);
     let fullwidth = props.fullwidth.unwrap_or(false);
-    let size = props.size.unwrap_or_default();
+    let size = props.size.or(defaults.table.size).unwrap_or_default();
     
-    let base_classes = vec!["table"];
-    let optional_classes = vec![
-        if bordered { Some("is-bordered".to_string()) } else { None },
-        if striped { Some("is-striped".to_string()) } else { None },
-        if narrow { Some("is-narrow".to_string()) } else { None },
-        if hoverable { Some("is-hoverable".to_string()) } else { None },
-        if fullwidth { Some("is-fullwidth".to_string()) } else { None },
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "table",
+        bordered.then_some("is-bordered"),
+        striped.then_some("is-striped"),
+        narrow.then_some("is-narrow"),
+        hoverable.then_some("is-hoverable"),
+        fullwidth.then_some("is-fullwidth"),
+        size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let table_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -63,6 +68,8 @@ pub struct TableContainerProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -71,9 +78,7 @@ pub struct TableContainerProps {
 
 #[component]
 pub fn TableContainer(props: TableContainerProps) -> Element {
-    let base_classes = vec!["table-container"];
-    let optional_classes = vec![props.class.clone()];
-    let final_class = build_class(&base_classes, &optional_classes);
+    let final_class = classes!["table-container", &props.helpers, &props.class];
     let style = props.style.as_deref().unwrap_or("");
 
     rsx! {
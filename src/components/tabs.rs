@@ -1,6 +1,12 @@
 use crate::theme::BulmaSize;
-use crate::utils::build_class;
+use crate::defaults::use_bulma_defaults;
+use crate::components::{Linkable, LinkableProps};
+use crate::helpers::Helpers;
+use crate::classes;
 use dioxus::prelude::*;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
+use crate::utils::ClassList;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TabsStyle {
@@ -21,6 +27,25 @@ impl TabsStyle {
     }
 }
 
+impl BulmaClass for TabsStyle {
+    fn name(&self) -> &'static str {
+        match self {
+            TabsStyle::Default => "",
+            TabsStyle::Boxed => "boxed",
+            TabsStyle::Toggle => "toggle",
+            TabsStyle::ToggleRounded => "toggle-rounded",
+        }
+    }
+
+    /// `ToggleRounded` builds on Bulma's toggle style and needs both classes.
+    fn add_class(&self, list: &mut ClassList) {
+        if *self == TabsStyle::ToggleRounded {
+            list.add("is-toggle");
+        }
+        list.add_prefixed(self.prefix(), self.name());
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TabsAlignment {
     Left,
@@ -38,6 +63,27 @@ impl TabsAlignment {
     }
 }
 
+impl BulmaClass for TabsAlignment {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(TabsStyle, TabsAlignment);
+
+named_enum!(TabsStyle {
+    Default => "default",
+    Boxed => "boxed",
+    Toggle => "toggle",
+    ToggleRounded => "toggle-rounded",
+});
+
+named_enum!(TabsAlignment {
+    Left => "left",
+    Centered => "centered",
+    Right => "right",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TabsProps {
     #[props(default)]
@@ -48,9 +94,20 @@ pub struct TabsProps {
     pub alignment: Option<TabsAlignment>,
     #[props(default)]
     pub fullwidth: Option<bool>,
+    /// Keeps the selected tab in this query string parameter. Tabs with a
+    /// `value` are then active while it matches and select it when clicked.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub query_key: Option<String>,
+    /// Value selected while the query parameter is missing.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub default_tab: Option<String>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub css_style: Option<String>,
@@ -59,27 +116,30 @@ pub struct TabsProps {
 
 #[component]
 pub fn Tabs(props: TabsProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let size = defaults.control_size(&defaults.tabs, props.size);
     let style = props.style.unwrap_or(TabsStyle::Default);
     let alignment = props.alignment.unwrap_or(TabsAlignment::Left);
     let fullwidth = props.fullwidth.unwrap_or(false);
+    #[cfg(feature = "router")]
+    {
+        let query = crate::router_helpers::use_query_binding(
+            props.query_key.clone(),
+            props.default_tab.clone().unwrap_or_default(),
+        );
+        use_context_provider(|| TabsQuery(query));
+    }
     
-    let base_classes = vec!["tabs"];
-    let optional_classes = vec![
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if style != TabsStyle::Default { 
-            if style == TabsStyle::ToggleRounded {
-                Some("is-toggle is-toggle-rounded".to_string())
-            } else {
-                Some(style.as_str().to_string())
-            }
-        } else { None },
-        if alignment != TabsAlignment::Left { Some(alignment.as_str().to_string()) } else { None },
-        if fullwidth { Some("is-fullwidth".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "tabs",
+        size,
+        style,
+        alignment,
+        fullwidth.then_some("is-fullwidth"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let tabs_style = props.css_style.as_deref().unwrap_or("");
 
     rsx! {
@@ -94,6 +154,11 @@ pub fn Tabs(props: TabsProps) -> Element {
     }
 }
 
+/// The query binding of the enclosing [`Tabs`], if it has a `query_key`.
+#[cfg(feature = "router")]
+#[derive(Clone, Copy)]
+struct TabsQuery(Option<crate::router_helpers::QueryState<String>>);
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TabProps {
     #[props(default)]
@@ -108,9 +173,19 @@ pub struct TabProps {
     #[cfg(feature = "router")]
     #[props(default, into)]
     pub to: crate::router_helpers::MaybeNav,
+    /// Derives `active` from the current route when `active` is unset.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub active_match: Option<crate::router_helpers::ActiveMatch>,
+    /// This tab's value in the query string of a [`Tabs`] with a `query_key`.
+    #[cfg(feature = "router")]
+    #[props(default)]
+    pub value: Option<String>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -119,16 +194,26 @@ pub struct TabProps {
 
 #[component]
 pub fn Tab(props: TabProps) -> Element {
+    #[cfg(feature = "router")]
+    let query = try_use_context::<TabsQuery>()
+        .and_then(|tabs| tabs.0)
+        .zip(props.value.clone());
+    #[cfg(feature = "router")]
+    let active = crate::router_helpers::resolve_active(
+        props.active.or(query.as_ref().map(|(query, value)| query.get() == *value)),
+        props.active_match.as_ref(),
+        &props.to,
+    );
+    #[cfg(not(feature = "router"))]
     let active = props.active.unwrap_or(false);
     let disabled = props.disabled.unwrap_or(false);
     
-    let base_classes = vec![];
-    let optional_classes = vec![
-        if active { Some("is-active".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        active.then_some("is-active"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let tab_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -136,33 +221,27 @@ pub fn Tab(props: TabProps) -> Element {
             class: "{final_class}",
             style: "{tab_style}",
             id: props.id.clone(),
-            
-            if let Some(href) = props.href {
-                if !disabled {
-                    a {
-                        href: "{href}",
-                        onclick: move |evt| {
-                            if let Some(handler) = &props.onclick {
-                                handler.call(evt);
-                            }
-                        },
-                        {props.children}
-                    }
-                } else {
-                    span { {props.children} }
-                }
-            } else {
-                a {
-                    onclick: move |evt| {
-                        if !disabled {
-                            if let Some(handler) = &props.onclick {
-                                handler.call(evt);
-                            }
+            {Linkable(LinkableProps {
+                href: props.href,
+                #[cfg(feature = "router")]
+                to: props.to,
+                disabled,
+                aria_current: active.then(|| "page".to_string()),
+                #[cfg(feature = "router")]
+                onclick: match query {
+                    Some((mut query, value)) => Some(EventHandler::new(move |evt| {
+                        query.set(value.clone());
+                        if let Some(handler) = &props.onclick {
+                            handler.call(evt);
                         }
-                    },
-                    {props.children}
-                }
-            }
+                    })),
+                    None => props.onclick,
+                },
+                #[cfg(not(feature = "router"))]
+                onclick: props.onclick,
+                children: props.children,
+                ..Default::default()
+            })}
         }
     }
 }
@@ -1,24 +1,42 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::components::{LinkFallback, Linkable, LinkableProps};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TagProps {
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
+    /// Shorthand for `shade: ColorShade::Light`.
     #[props(default)]
     pub light: Option<bool>,
     #[props(default)]
     pub rounded: Option<bool>,
     #[props(default)]
+    pub href: Option<String>,
+    /// If present, use router navigation instead of href
+    #[cfg(feature = "router")]
+    #[props(default, into)]
+    pub to: crate::router_helpers::MaybeNav,
+    #[props(default)]
     pub delete: Option<bool>,
     #[props(default)]
     pub ondelete: Option<EventHandler<MouseEvent>>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -27,22 +45,27 @@ pub struct TagProps {
 
 #[component]
 pub fn Tag(props: TagProps) -> Element {
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.tag.color);
     let light = props.light.unwrap_or(false);
-    let rounded = props.rounded.unwrap_or(false);
+    let rounded = props.rounded.or(defaults.tag.rounded).unwrap_or(false);
     let delete = props.delete.unwrap_or(false);
-    let size = props.size.unwrap_or_default();
+    let size = defaults.control_size(&defaults.tag, props.size);
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["tag"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if light { Some("is-light".to_string()) } else { None },
-        if rounded { Some("is-rounded".to_string()) } else { None },
-        if delete { Some("is-delete".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "tag",
+        color,
+        color.zip(props.shade).map(|(c, s)| s.fill_class(c)),
+        size,
+        light.then_some("is-light"),
+        rounded.then_some("is-rounded"),
+        delete.then_some("is-delete"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let tag_style = props.style.as_deref().unwrap_or("");
 
     if delete {
@@ -59,14 +82,17 @@ pub fn Tag(props: TagProps) -> Element {
             }
         }
     } else {
-        rsx! {
-            span {
-                class: "{final_class}",
-                style: "{tag_style}",
-                id: props.id.clone(),
-                {props.children}
-            }
-        }
+        Linkable(LinkableProps {
+            href: props.href,
+            #[cfg(feature = "router")]
+            to: props.to,
+            fallback: LinkFallback::Span,
+            id: props.id,
+            class: Some(final_class),
+            style: props.style,
+            children: props.children,
+            ..Default::default()
+        })
     }
 }
 
@@ -77,6 +103,8 @@ pub struct TagsProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -87,13 +115,13 @@ pub struct TagsProps {
 pub fn Tags(props: TagsProps) -> Element {
     let addons = props.addons.unwrap_or(false);
     
-    let base_classes = vec!["tags"];
-    let optional_classes = vec![
-        if addons { Some("has-addons".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "tags",
+        addons.then_some("has-addons"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let tags_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
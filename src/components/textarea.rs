@@ -1,6 +1,8 @@
 use dioxus::prelude::*;
-use crate::theme::{BulmaColor, BulmaSize};
-use crate::utils::build_class;
+use crate::theme::{BulmaColor, BulmaSize, ColorShade};
+use crate::defaults::use_bulma_defaults;
+use crate::helpers::Helpers;
+use crate::classes;
 
 #[derive(Props, Clone, PartialEq)]
 pub struct TextareaProps {
@@ -10,6 +12,10 @@ pub struct TextareaProps {
     pub placeholder: Option<String>,
     #[props(default)]
     pub color: Option<BulmaColor>,
+    /// Light, dark or numbered palette variant of `color`, applied to the
+    /// border inline since Bulma has no shaded modifiers for it.
+    #[props(default)]
+    pub shade: Option<ColorShade>,
     #[props(default)]
     pub size: Option<BulmaSize>,
     #[props(default)]
@@ -30,9 +36,14 @@ pub struct TextareaProps {
     pub onfocus: Option<EventHandler<FocusEvent>>,
     #[props(default)]
     pub onblur: Option<EventHandler<FocusEvent>>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -40,21 +51,26 @@ pub struct TextareaProps {
 
 #[component]
 pub fn Textarea(props: TextareaProps) -> Element {
-    let size = props.size.unwrap_or_default();
+    let defaults = use_bulma_defaults();
+    let color = props.color.or(defaults.textarea.color);
+    let size = defaults.control_size(&defaults.textarea, props.size);
     let disabled = props.disabled.unwrap_or(false);
     let readonly = props.readonly.unwrap_or(false);
     let has_fixed_size = props.has_fixed_size.unwrap_or(false);
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["textarea"];
-    let optional_classes = vec![
-        props.color.map(|c| format!("is-{}", c.as_str())),
-        if size != BulmaSize::Normal { Some(size.as_class().to_string()) } else { None },
-        if has_fixed_size { Some("has-fixed-size".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "textarea",
+        color,
+        size,
+        has_fixed_size.then_some("has-fixed-size"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
-    let textarea_style = props.style.as_deref().unwrap_or("");
+    let shade_style = color.zip(props.shade).map(|(c, s)| format!("border-color: {}; ", s.color_value(c)));
+    let textarea_style = format!("{}{}", shade_style.unwrap_or_default(), props.style.as_deref().unwrap_or(""));
 
     rsx! {
         textarea {
@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TileSize {
@@ -36,6 +39,29 @@ impl TileSize {
     }
 }
 
+impl BulmaClass for TileSize {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(TileSize);
+
+named_enum!(TileSize {
+    Is1 => "1",
+    Is2 => "2",
+    Is3 => "3",
+    Is4 => "4",
+    Is5 => "5",
+    Is6 => "6",
+    Is7 => "7",
+    Is8 => "8",
+    Is9 => "9",
+    Is10 => "10",
+    Is11 => "11",
+    Is12 => "12",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TileProps {
     #[props(default)]
@@ -51,6 +77,8 @@ pub struct TileProps {
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -64,17 +92,17 @@ pub fn Tile(props: TileProps) -> Element {
     let child = props.child.unwrap_or(false);
     let vertical = props.vertical.unwrap_or(false);
     
-    let base_classes = vec!["tile"];
-    let optional_classes = vec![
-        if ancestor { Some("is-ancestor".to_string()) } else { None },
-        if parent { Some("is-parent".to_string()) } else { None },
-        if child { Some("is-child".to_string()) } else { None },
-        if vertical { Some("is-vertical".to_string()) } else { None },
-        props.size.map(|s| s.as_str().to_string()),
-        props.class.clone(),
+    let final_class = classes![
+        "tile",
+        ancestor.then_some("is-ancestor"),
+        parent.then_some("is-parent"),
+        child.then_some("is-child"),
+        vertical.then_some("is-vertical"),
+        props.size,
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let tile_style = props.style.as_deref().unwrap_or("");
 
     rsx! {
@@ -1,5 +1,8 @@
 use dioxus::prelude::*;
-use crate::utils::build_class;
+use crate::helpers::Helpers;
+use crate::classes;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::named_enum;
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TitleSize {
@@ -35,15 +38,37 @@ impl TitleSize {
     }
 }
 
+impl BulmaClass for TitleSize {
+    fn name(&self) -> &'static str {
+        self.as_str().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(TitleSize);
+
+named_enum!(TitleSize {
+    Is1 => "1",
+    Is2 => "2",
+    Is3 => "3",
+    Is4 => "4",
+    Is5 => "5",
+    Is6 => "6",
+});
+
 #[derive(Props, Clone, PartialEq)]
 pub struct TitleProps {
     #[props(default = TitleSize::Is3)]
     pub size: TitleSize,
     #[props(default)]
     pub spaced: Option<bool>,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -53,16 +78,17 @@ pub struct TitleProps {
 #[component]
 pub fn Title(props: TitleProps) -> Element {
     let spaced = props.spaced.unwrap_or(false);
+    let skeleton = props.skeleton.unwrap_or(false);
     
-    let base_classes = vec!["title"];
-    
-    let optional_classes = vec![
-        Some(props.size.as_str().to_string()),
-        if spaced { Some("is-spaced".to_string()) } else { None },
-        props.class.clone(),
+    let final_class = classes![
+        "title",
+        props.size,
+        spaced.then_some("is-spaced"),
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let title_style = props.style.as_deref().unwrap_or("");
 
     match props.size.as_tag() {
@@ -121,9 +147,14 @@ pub fn Title(props: TitleProps) -> Element {
 pub struct SubtitleProps {
     #[props(default = TitleSize::Is5)]
     pub size: TitleSize,
+    /// Renders a loading placeholder in place of the content.
+    #[props(default)]
+    pub skeleton: Option<bool>,
     #[props(default)]
     pub id: Option<String>,
     #[props(default)]
+    pub helpers: Helpers,
+    #[props(default)]
     pub class: Option<String>,
     #[props(default)]
     pub style: Option<String>,
@@ -132,14 +163,15 @@ pub struct SubtitleProps {
 
 #[component]
 pub fn Subtitle(props: SubtitleProps) -> Element {
-    let base_classes = vec!["subtitle"];
-    
-    let optional_classes = vec![
-        Some(props.size.as_str().to_string()),
-        props.class.clone(),
+    let skeleton = props.skeleton.unwrap_or(false);
+    let final_class = classes![
+        "subtitle",
+        props.size,
+        skeleton.then_some("is-skeleton"),
+        &props.helpers,
+        &props.class,
     ];
     
-    let final_class = build_class(&base_classes, &optional_classes);
     let subtitle_style = props.style.as_deref().unwrap_or("");
 
     match props.size.as_tag() {
@@ -0,0 +1,117 @@
+//! App-wide defaults for component props.
+//!
+//! Components fall back to a [`BulmaDefaults`] provided by
+//! [`BulmaProvider`](crate::BulmaProvider) whenever a prop is left unset, so
+//! an app that wants small, outlined, link-colored buttons everywhere says so
+//! once:
+//!
+//! ```rust,ignore
+//! BulmaProvider {
+//!     defaults: BulmaDefaults {
+//!         density: Density::Compact,
+//!         button: ComponentDefaults {
+//!             color: Some(BulmaColor::Link),
+//!             outlined: Some(true),
+//!             ..Default::default()
+//!         },
+//!         ..Default::default()
+//!     },
+//!     Button { "Outlined, link-colored and small" }
+//!     Button { color: BulmaColor::Danger, outlined: false, "Explicit props still win" }
+//! }
+//! ```
+
+use crate::enums::{impl_name_display, named_enum};
+use crate::theme::{BulmaColor, BulmaSize};
+use dioxus::prelude::*;
+
+/// How tightly controls are laid out across the app.
+///
+/// Density picks the size of buttons, tags, form controls, tabs, breadcrumbs
+/// and pagination when neither their `size` prop nor their
+/// [`ComponentDefaults::size`] is set, and makes tables narrow when compact.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Density {
+    /// Small controls and narrow tables.
+    Compact,
+    /// Bulma's regular sizes.
+    #[default]
+    Comfortable,
+    /// Medium controls.
+    Spacious,
+}
+
+impl Density {
+    /// The control size this density stands for.
+    pub fn size(&self) -> BulmaSize {
+        match self {
+            Density::Compact => BulmaSize::Small,
+            Density::Comfortable => BulmaSize::Normal,
+            Density::Spacious => BulmaSize::Medium,
+        }
+    }
+}
+
+named_enum!(Density {
+    Compact => "compact",
+    Comfortable => "comfortable",
+    Spacious => "spacious",
+});
+
+impl_name_display!(Density);
+
+/// Fallback values for one component's props. Fields a component doesn't
+/// have are ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct ComponentDefaults {
+    pub color: Option<BulmaColor>,
+    pub size: Option<BulmaSize>,
+    pub rounded: Option<bool>,
+    pub outlined: Option<bool>,
+}
+
+/// Per-component prop defaults plus a global [`Density`], provided to the
+/// whole app through [`BulmaProvider`](crate::BulmaProvider)'s `defaults`
+/// prop and read with [`use_bulma_defaults`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct BulmaDefaults {
+    pub density: Density,
+    pub breadcrumb: ComponentDefaults,
+    pub button: ComponentDefaults,
+    pub buttons: ComponentDefaults,
+    pub content: ComponentDefaults,
+    pub delete: ComponentDefaults,
+    pub file: ComponentDefaults,
+    pub footer: ComponentDefaults,
+    pub hero: ComponentDefaults,
+    pub icon: ComponentDefaults,
+    pub image: ComponentDefaults,
+    pub input: ComponentDefaults,
+    pub message: ComponentDefaults,
+    pub navbar: ComponentDefaults,
+    pub notification: ComponentDefaults,
+    pub pagination: ComponentDefaults,
+    pub panel: ComponentDefaults,
+    pub progress: ComponentDefaults,
+    pub select: ComponentDefaults,
+    pub table: ComponentDefaults,
+    pub tabs: ComponentDefaults,
+    pub tag: ComponentDefaults,
+    pub textarea: ComponentDefaults,
+}
+
+impl BulmaDefaults {
+    /// The size of a density-aware control: the prop, else the component's
+    /// default, else the density's size.
+    pub fn control_size(&self, component: &ComponentDefaults, size: Option<BulmaSize>) -> BulmaSize {
+        size.or(component.size).unwrap_or(self.density.size())
+    }
+}
+
+/// Returns the [`BulmaDefaults`] of the nearest `BulmaProvider`, or the
+/// built-in defaults outside of one.
+pub fn use_bulma_defaults() -> BulmaDefaults {
+    try_use_context::<Signal<BulmaDefaults>>()
+        .map(|defaults| defaults())
+        .unwrap_or_default()
+}
@@ -0,0 +1,160 @@
+//! Names, parsing and (optionally) serde support for the public enums.
+//!
+//! Every option enum has a stable kebab-case name per variant, matching
+//! Bulma's own naming (`"primary"`, `"three-quarters"`, `"16by9"`), listed
+//! in an `ALL` constant and exposed through [`BulmaEnum`]. Names parse back
+//! with `FromStr`, which also accepts the rendered class (`"is-primary"`) of
+//! modifier enums. With the `serde` feature enabled the enums serialize to
+//! and deserialize from the same names, so they can be read from JSON or
+//! TOML configuration:
+//!
+//! ```rust
+//! use dioxus_bulma::{BulmaColor, BulmaEnum, BulmaSize};
+//!
+//! assert_eq!("primary".parse::<BulmaColor>(), Ok(BulmaColor::Primary));
+//! assert_eq!("is-large".parse::<BulmaSize>(), Ok(BulmaSize::Large));
+//! assert_eq!(BulmaSize::Small.variant_name(), "small");
+//!
+//! // An enum picker:
+//! let options: Vec<&str> = BulmaSize::ALL.iter().map(BulmaSize::variant_name).collect();
+//! assert_eq!(options, ["small", "normal", "medium", "large"]);
+//! ```
+//!
+//! `BulmaColor::Custom` colors have no fixed name and don't round-trip:
+//! parsing `"brand"` fails, and serializing a custom color returns an error
+//! rather than a name that couldn't be read back. Store the name yourself and
+//! map it to its `CustomColor`.
+
+use std::fmt;
+
+/// An enum with a fixed set of named variants.
+pub trait BulmaEnum: Sized + Copy + PartialEq + fmt::Display + 'static {
+    /// Every variant, in declaration order.
+    fn all() -> &'static [Self];
+
+    /// The variant's kebab-case name, used by `FromStr` and serde.
+    fn variant_name(&self) -> &'static str;
+
+    /// Parses a variant's name or its `Display` output.
+    fn from_name(name: &str) -> Result<Self, ParseEnumError> {
+        Self::all()
+            .iter()
+            .find(|variant| {
+                variant.variant_name() == name || {
+                    let shown = variant.to_string();
+                    !shown.is_empty() && shown == name
+                }
+            })
+            .copied()
+            .ok_or_else(|| ParseEnumError { type_name: short_type_name::<Self>(), value: name.to_string() })
+    }
+}
+
+fn short_type_name<T>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    name.rsplit("::").next().unwrap_or(name)
+}
+
+/// Error returned when parsing an unknown variant name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    type_name: &'static str,
+    value: String,
+}
+
+impl ParseEnumError {
+    /// The name of the enum that failed to parse.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The rejected input.
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`", self.type_name, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+/// Implements [`BulmaEnum`], `ALL`, `FromStr` and serde support for an enum
+/// from its variant names. Extra match arms cover variants carrying data,
+/// which are left out of `ALL`.
+macro_rules! named_enum {
+    ($ty:ident { $($variant:ident => $name:literal),+ $(,)? } $(, $pat:pat => $expr:expr)*) => {
+        impl $ty {
+            /// Every variant, in declaration order.
+            pub const ALL: [$ty; [$($name),+].len()] = [$($ty::$variant),+];
+        }
+
+        impl $crate::enums::BulmaEnum for $ty {
+            fn all() -> &'static [Self] {
+                &Self::ALL
+            }
+
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    $($ty::$variant => $name,)+
+                    $($pat => $expr,)*
+                }
+            }
+        }
+
+        $crate::enums::impl_from_str!($ty);
+    };
+}
+
+/// Implements `FromStr` and serde support for a [`BulmaEnum`].
+macro_rules! impl_from_str {
+    ($ty:ty) => {
+        impl ::std::str::FromStr for $ty {
+            type Err = $crate::enums::ParseEnumError;
+
+            fn from_str(name: &str) -> Result<Self, Self::Err> {
+                <$ty as $crate::enums::BulmaEnum>::from_name(name)
+            }
+        }
+
+        /// Fails for names that wouldn't deserialize, such as a
+        /// [`BulmaColor::Custom`](crate::BulmaColor::Custom) color.
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $ty {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                let name = $crate::enums::BulmaEnum::variant_name(self);
+                if let Err(err) = <$ty as $crate::enums::BulmaEnum>::from_name(name) {
+                    return Err(::serde::ser::Error::custom(format!("{err} can't be serialized")));
+                }
+                serializer.serialize_str(name)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $ty {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let name = <::std::borrow::Cow<'de, str> as ::serde::Deserialize>::deserialize(deserializer)?;
+                name.parse().map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+/// Implements `Display` as the variant name, for enums that don't render
+/// as a class.
+macro_rules! impl_name_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ::std::fmt::Display for $ty {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    f.write_str($crate::enums::BulmaEnum::variant_name(self))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use {impl_from_str, impl_name_display, named_enum};
@@ -0,0 +1,392 @@
+//! Typed Bulma helper classes.
+//!
+//! Bulma's [helpers](https://bulma.io/documentation/helpers/) cover spacing,
+//! typography, color and visibility. Passing them as free-form `class`
+//! strings works, but typos fail silently. [`Helpers`] builds the same
+//! classes from typed values and is accepted by the `helpers` prop of every
+//! component:
+//!
+//! ```rust,ignore
+//! Button {
+//!     helpers: Helpers::new()
+//!         .margin(Side::X, 3)
+//!         .text_align(TextAlignment::Centered)
+//!         .weight(FontWeight::Bold),
+//!     "Save"
+//! }
+//! ```
+
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::{impl_name_display, named_enum};
+use crate::responsive::{Breakpoint, Responsive, TextAlignment};
+use crate::theme::{BulmaColor, ColorShade};
+use crate::utils::{ClassList, ClassPart};
+
+/// Which sides a spacing helper applies to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Side {
+    All,
+    Top,
+    Right,
+    Bottom,
+    Left,
+    /// Left and right.
+    X,
+    /// Top and bottom.
+    Y,
+}
+
+impl Side {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Side::All => "",
+            Side::Top => "t",
+            Side::Right => "r",
+            Side::Bottom => "b",
+            Side::Left => "l",
+            Side::X => "x",
+            Side::Y => "y",
+        }
+    }
+}
+
+named_enum!(Side {
+    All => "all",
+    Top => "top",
+    Right => "right",
+    Bottom => "bottom",
+    Left => "left",
+    X => "x",
+    Y => "y",
+});
+
+impl_name_display!(Side);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontWeight {
+    Light,
+    Normal,
+    Medium,
+    Semibold,
+    Bold,
+    Extrabold,
+}
+
+impl FontWeight {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            FontWeight::Light => "has-text-weight-light",
+            FontWeight::Normal => "has-text-weight-normal",
+            FontWeight::Medium => "has-text-weight-medium",
+            FontWeight::Semibold => "has-text-weight-semibold",
+            FontWeight::Bold => "has-text-weight-bold",
+            FontWeight::Extrabold => "has-text-weight-extrabold",
+        }
+    }
+}
+
+impl BulmaClass for FontWeight {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("has-text-weight-").unwrap_or_default()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "has-text-weight-"
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextTransform {
+    Capitalized,
+    Lowercase,
+    Uppercase,
+    Italic,
+    Underlined,
+}
+
+impl TextTransform {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TextTransform::Capitalized => "is-capitalized",
+            TextTransform::Lowercase => "is-lowercase",
+            TextTransform::Uppercase => "is-uppercase",
+            TextTransform::Italic => "is-italic",
+            TextTransform::Underlined => "is-underlined",
+        }
+    }
+}
+
+impl BulmaClass for TextTransform {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FontFamily {
+    SansSerif,
+    Monospace,
+    Primary,
+    Secondary,
+    Code,
+}
+
+impl FontFamily {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            FontFamily::SansSerif => "is-family-sans-serif",
+            FontFamily::Monospace => "is-family-monospace",
+            FontFamily::Primary => "is-family-primary",
+            FontFamily::Secondary => "is-family-secondary",
+            FontFamily::Code => "is-family-code",
+        }
+    }
+}
+
+impl BulmaClass for FontFamily {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("is-family-").unwrap_or_default()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "is-family-"
+    }
+}
+
+/// CSS `display` value, rendered as `is-<display>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DisplayMode {
+    Block,
+    Flex,
+    Inline,
+    InlineBlock,
+    InlineFlex,
+}
+
+impl DisplayMode {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            DisplayMode::Block => "is-block",
+            DisplayMode::Flex => "is-flex",
+            DisplayMode::Inline => "is-inline",
+            DisplayMode::InlineBlock => "is-inline-block",
+            DisplayMode::InlineFlex => "is-inline-flex",
+        }
+    }
+}
+
+impl BulmaClass for DisplayMode {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(FontWeight, TextTransform, FontFamily, DisplayMode);
+
+named_enum!(FontWeight {
+    Light => "light",
+    Normal => "normal",
+    Medium => "medium",
+    Semibold => "semibold",
+    Bold => "bold",
+    Extrabold => "extrabold",
+});
+
+named_enum!(TextTransform {
+    Capitalized => "capitalized",
+    Lowercase => "lowercase",
+    Uppercase => "uppercase",
+    Italic => "italic",
+    Underlined => "underlined",
+});
+
+named_enum!(FontFamily {
+    SansSerif => "sans-serif",
+    Monospace => "monospace",
+    Primary => "primary",
+    Secondary => "secondary",
+    Code => "code",
+});
+
+named_enum!(DisplayMode {
+    Block => "block",
+    Flex => "flex",
+    Inline => "inline",
+    InlineBlock => "inline-block",
+    InlineFlex => "inline-flex",
+});
+
+/// A set of Bulma helper classes, built with chained calls.
+///
+/// Spacing sizes follow Bulma's `0`–`6` scale; larger values are capped at
+/// `6`. Text sizes follow Bulma's `1`–`7` scale and are clamped to it.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Helpers {
+    classes: Vec<String>,
+}
+
+impl Helpers {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn push(mut self, class: impl Into<String>) -> Self {
+        let class = class.into();
+        if !class.is_empty() && !self.classes.contains(&class) {
+            self.classes.push(class);
+        }
+        self
+    }
+
+    /// `m-3`, `mx-4`, ... Sizes are capped at `6`.
+    pub fn margin(self, side: Side, size: u8) -> Self {
+        self.push(format!("m{}-{}", side.as_str(), size.min(6)))
+    }
+
+    /// `m-auto`, `mx-auto`, ...
+    pub fn margin_auto(self, side: Side) -> Self {
+        self.push(format!("m{}-auto", side.as_str()))
+    }
+
+    /// `p-3`, `py-2`, ... Sizes are capped at `6`.
+    pub fn padding(self, side: Side, size: u8) -> Self {
+        self.push(format!("p{}-{}", side.as_str(), size.min(6)))
+    }
+
+    /// `is-size-1` (largest) to `is-size-7` (smallest), optionally per
+    /// breakpoint.
+    pub fn text_size(self, size: impl Into<Responsive<u8>>) -> Self {
+        match size.into().to_class(|size| format!("is-size-{}", size.clamp(&1, &7))) {
+            Some(class) => self.push(class),
+            None => self,
+        }
+    }
+
+    /// `has-text-centered`, ..., optionally per breakpoint.
+    pub fn text_align(self, align: impl Into<Responsive<TextAlignment>>) -> Self {
+        match align.into().to_text_align_class() {
+            Some(class) => self.push(class),
+            None => self,
+        }
+    }
+
+    /// `has-text-weight-bold`, ...
+    pub fn weight(self, weight: FontWeight) -> Self {
+        self.push(weight.as_class())
+    }
+
+    /// `is-uppercase`, `is-italic`, ...
+    pub fn transform(self, transform: TextTransform) -> Self {
+        self.push(transform.as_class())
+    }
+
+    /// `is-family-monospace`, ...
+    pub fn family(self, family: FontFamily) -> Self {
+        self.push(family.as_class())
+    }
+
+    /// `has-text-primary`, ...
+    pub fn text_color(self, color: BulmaColor) -> Self {
+        self.push(format!("has-text-{}", color.as_str()))
+    }
+
+    /// `has-text-primary-dark`, `has-text-primary-40`, ...
+    pub fn text_shade(self, color: BulmaColor, shade: ColorShade) -> Self {
+        self.push(shade.text_class(color))
+    }
+
+    /// `has-background-primary`, ...
+    pub fn background(self, color: BulmaColor) -> Self {
+        self.push(format!("has-background-{}", color.as_str()))
+    }
+
+    /// `has-background-primary-light`, `has-background-primary-90`, ...
+    pub fn background_shade(self, color: BulmaColor, shade: ColorShade) -> Self {
+        self.push(shade.text_class(color).replacen("has-text-", "has-background-", 1))
+    }
+
+    /// `is-block`, `is-flex-mobile`, ..., optionally per breakpoint.
+    pub fn display(self, display: impl Into<Responsive<DisplayMode>>) -> Self {
+        match display.into().to_class(DisplayMode::as_class) {
+            Some(class) => self.push(class),
+            None => self,
+        }
+    }
+
+    /// `is-hidden` everywhere.
+    pub fn hidden(self) -> Self {
+        self.push("is-hidden")
+    }
+
+    /// `is-hidden-mobile`, `is-hidden-desktop`, ...
+    pub fn hidden_at(self, breakpoint: Breakpoint) -> Self {
+        self.push(format!("is-hidden-{}", breakpoint.as_str()))
+    }
+
+    /// `is-invisible`: hidden but still taking up space.
+    pub fn invisible(self) -> Self {
+        self.push("is-invisible")
+    }
+
+    /// `is-sr-only`: hidden visually but read by screen readers.
+    pub fn sr_only(self) -> Self {
+        self.push("is-sr-only")
+    }
+
+    /// `is-clipped`
+    pub fn clipped(self) -> Self {
+        self.push("is-clipped")
+    }
+
+    /// `is-radiusless`
+    pub fn radiusless(self) -> Self {
+        self.push("is-radiusless")
+    }
+
+    /// `is-shadowless`
+    pub fn shadowless(self) -> Self {
+        self.push("is-shadowless")
+    }
+
+    /// `is-unselectable`
+    pub fn unselectable(self) -> Self {
+        self.push("is-unselectable")
+    }
+
+    /// `is-clickable`
+    pub fn clickable(self) -> Self {
+        self.push("is-clickable")
+    }
+
+    /// `is-relative`
+    pub fn relative(self) -> Self {
+        self.push("is-relative")
+    }
+
+    /// The classes in the order they were added.
+    pub fn classes(&self) -> &[String] {
+        &self.classes
+    }
+
+    /// Returns `true` if no helper has been added.
+    pub fn is_empty(&self) -> bool {
+        self.classes.is_empty()
+    }
+
+    /// The space-separated class string, or `None` if empty.
+    pub fn to_class(&self) -> Option<String> {
+        if self.classes.is_empty() {
+            None
+        } else {
+            Some(self.classes.join(" "))
+        }
+    }
+}
+
+impl ClassPart for &Helpers {
+    fn add_to(self, list: &mut ClassList) {
+        for class in &self.classes {
+            list.add(class);
+        }
+    }
+}
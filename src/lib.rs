@@ -13,7 +13,12 @@
 //! ```
 
 pub mod components;
+pub mod defaults;
+pub mod enums;
+pub mod helpers;
+pub mod modifier;
 pub mod prelude;
+pub mod responsive;
 pub mod theme;
 pub mod utils;
 
@@ -22,9 +27,14 @@ pub mod router_helpers;
 
 // Re-export theme components at top level (no naming conflicts)
 pub use theme::*;
+pub use defaults::{use_bulma_defaults, BulmaDefaults, ComponentDefaults, Density};
+pub use enums::{BulmaEnum, ParseEnumError};
+pub use modifier::{BulmaClass, Prefixed};
+pub use helpers::{DisplayMode, FontFamily, FontWeight, Helpers, Side, TextTransform};
+pub use responsive::{Breakpoint, Responsive, TextAlignment};
 
 #[cfg(feature = "router")]
-pub use router_helpers::MaybeNav;
+pub use router_helpers::{use_query_state, ActiveMatch, MaybeNav, QueryState};
 
 // Re-export dioxus for convenience, but avoid ImageSize conflict
 pub use dioxus::prelude::{component, Element, Props, rsx, EventHandler, MouseEvent, FormEvent};
@@ -0,0 +1,151 @@
+//! A common interface for Bulma's modifier enums.
+//!
+//! Every enum that stands for a Bulma modifier class — colors, sizes,
+//! alignments, column widths and so on — implements [`BulmaClass`]. Its class
+//! is split into a prefix (`is-`, `has-`, `has-text-`, ...) and a bare name
+//! (`primary`, `three-quarters`, `centered`), so the same value can be
+//! rendered under a different prefix, like a column offset:
+//!
+//! ```rust
+//! use dioxus_bulma::components::ColumnSize;
+//! use dioxus_bulma::{classes, BulmaClass, BulmaColor};
+//!
+//! assert_eq!(BulmaColor::Primary.class(), "is-primary");
+//! assert_eq!(ColumnSize::Half.to_string(), "is-half");
+//! assert_eq!(classes![ColumnSize::Half.prefixed("is-offset-")], "is-offset-half");
+//! ```
+//!
+//! Extension components can accept any modifier generically:
+//!
+//! ```rust,ignore
+//! #[derive(Props, Clone, PartialEq)]
+//! struct BadgeProps<M: BulmaClass + Clone + PartialEq + 'static> {
+//!     modifier: M,
+//!     children: Element,
+//! }
+//!
+//! fn Badge<M: BulmaClass + Clone + PartialEq + 'static>(props: BadgeProps<M>) -> Element {
+//!     rsx! { span { class: classes!["badge", props.modifier], {props.children} } }
+//! }
+//! ```
+
+use crate::responsive::Responsive;
+use crate::utils::{ClassList, ClassPart};
+use std::fmt;
+
+/// A Bulma modifier that renders as a CSS class.
+///
+/// Values that stand for Bulma's default, such as `BulmaSize::Normal` or a
+/// left alignment, have an empty [`name`](BulmaClass::name) and render no
+/// class. `Display` prints the same string as [`class`](BulmaClass::class).
+pub trait BulmaClass: Copy + fmt::Display {
+    /// The modifier without its prefix, e.g. `"primary"` or `"one-third"`.
+    fn name(&self) -> &'static str;
+
+    /// The prefix that turns [`name`](BulmaClass::name) into a class.
+    fn prefix(&self) -> &'static str {
+        "is-"
+    }
+
+    /// Appends this modifier's class to `list`.
+    fn add_class(&self, list: &mut ClassList) {
+        list.add_prefixed(self.prefix(), self.name());
+    }
+
+    /// The class, e.g. `"is-primary"`.
+    fn class(&self) -> String {
+        let mut list = ClassList::new();
+        self.add_class(&mut list);
+        list.into_string()
+    }
+
+    /// The modifier under a different prefix, e.g. `"is-offset-"` for a
+    /// column offset. Usable anywhere a class is, including `classes!`.
+    fn prefixed(self, prefix: &'static str) -> Prefixed<Self> {
+        Prefixed { prefix, value: self }
+    }
+}
+
+/// A modifier rendered under a custom prefix; see [`BulmaClass::prefixed`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Prefixed<T> {
+    prefix: &'static str,
+    value: T,
+}
+
+impl<T: BulmaClass> ClassPart for Prefixed<T> {
+    fn add_to(self, list: &mut ClassList) {
+        list.add_prefixed(self.prefix, self.value.name());
+    }
+}
+
+impl<T: BulmaClass> fmt::Display for Prefixed<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.name().is_empty() {
+            return Ok(());
+        }
+        write!(f, "{}{}", self.prefix, self.value.name())
+    }
+}
+
+impl<T: BulmaClass> ClassPart for T {
+    fn add_to(self, list: &mut ClassList) {
+        self.add_class(list);
+    }
+}
+
+impl<T: BulmaClass> Responsive<T> {
+    /// The same values rendered under a different prefix, e.g. column
+    /// offsets: `is-offset-half is-offset-one-third-desktop`.
+    pub fn prefixed(&self, prefix: &'static str) -> Prefixed<&Self> {
+        Prefixed { prefix, value: self }
+    }
+
+    fn add_classes(&self, list: &mut ClassList, prefix: Option<&str>) {
+        if let Some(base) = self.base() {
+            match prefix {
+                Some(prefix) => {
+                    list.add_prefixed(prefix, base.name());
+                }
+                None => base.add_class(list),
+            }
+        }
+        for (breakpoint, value) in self.overrides() {
+            if !value.name().is_empty() {
+                let prefix = prefix.unwrap_or(value.prefix());
+                list.add_joined(&[prefix, value.name(), "-", breakpoint.as_str()]);
+            }
+        }
+    }
+}
+
+/// Renders the base value's class and `<class>-<breakpoint>` for each
+/// override, without allocating.
+impl<T: BulmaClass> ClassPart for &Responsive<T> {
+    fn add_to(self, list: &mut ClassList) {
+        self.add_classes(list, None);
+    }
+}
+
+impl<T: BulmaClass> ClassPart for Prefixed<&Responsive<T>> {
+    fn add_to(self, list: &mut ClassList) {
+        self.value.add_classes(list, Some(self.prefix));
+    }
+}
+
+/// Implements `Display` for [`BulmaClass`] types as their class.
+macro_rules! impl_class_display {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl ::std::fmt::Display for $ty {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    let mut list = $crate::utils::ClassList::new();
+                    $crate::modifier::BulmaClass::add_class(self, &mut list);
+                    f.write_str(list.as_str())
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_class_display;
@@ -33,11 +33,24 @@
 pub use dioxus::prelude::{component, Element, Props, rsx, EventHandler, MouseEvent, FormEvent, use_signal, use_effect, use_memo, use_callback};
 
 // Theme system
-pub use crate::theme::{BulmaTheme, BulmaColor, BulmaSize, BulmaProvider};
+pub use crate::theme::{BulmaTheme, BulmaColor, BulmaSize, ColorShade, BulmaProvider, BulmaCssSource, ThemeScope};
+pub use crate::theme::{use_bulma_theme, BulmaThemeContext, ThemeStorage, BulmaThemeConfig, ColorHsl, CustomColor};
+#[cfg(feature = "web")]
+pub use crate::theme::LocalStorage;
+pub use crate::defaults::{use_bulma_defaults, BulmaDefaults, ComponentDefaults, Density};
+pub use crate::enums::BulmaEnum;
+pub use crate::modifier::BulmaClass;
+pub use crate::helpers::{DisplayMode, FontFamily, FontWeight, Helpers, Side, TextTransform};
+pub use crate::responsive::{Breakpoint, Responsive, TextAlignment};
+#[cfg(feature = "router")]
+pub use crate::router_helpers::{use_query_state, ActiveMatch, QueryState};
+#[cfg(feature = "router")]
+pub use crate::components::{AutoBreadcrumb, BreadcrumbLabel, use_query_pagination};
 
 // Layout Components
 pub use crate::components::{
-    Container, Columns, Column, Section, Hero, HeroBody, HeroHead, HeroFoot,
+    Container, Columns, Column, Grid, FixedGrid, GridColumns, Cell, Section,
+    Footer, FooterColumns, FooterLinkGroup, FooterLink, Hero, HeroBody, HeroHead, HeroFoot,
     Level, LevelLeft, LevelRight, LevelItem,
     Media, MediaLeft, MediaContent, MediaRight,
     Tile
@@ -48,6 +61,7 @@ pub use crate::components::{
     BulmaBox, Block,
     Button, Buttons, ButtonsAlignment,
     Content, Delete, Icon, Image, Notification, Progress,
+    SkeletonBlock, SkeletonLines,
     Table, TableContainer,
     Tag, Tags,
     TitleSize,
@@ -67,12 +81,17 @@ pub use crate::components::{
 pub use crate::components::{
     Breadcrumb, BreadcrumbItem,
     Card, CardHeader, CardHeaderTitle, CardContent, CardFooter, CardFooterItem,
+    DataTable, TableColumn, TableSort, SortDirection, SelectionMode, RowKey,
     Dropdown, DropdownTrigger, DropdownMenu, DropdownItem, DropdownDivider,
+    Linkable, LinkFallback,
     Menu, MenuLabel, MenuList, MenuItem,
     Message, MessageHeader, MessageBody,
     Modal, ModalCard, ModalCardHead, ModalCardBody, ModalCardFoot,
-    Navbar, NavbarBrand, NavbarMenu, NavbarStart, NavbarEnd, NavbarItem,
+    Navbar, NavbarBrand, NavbarBurger, NavbarMenu, NavbarStart, NavbarEnd, NavbarItem,
+    NavbarDropdown, NavbarLink, NavbarDivider,
+    NavbarContext, use_navbar,
     Pagination, PaginationPrevious, PaginationList, PaginationNext, PaginationLink, PaginationEllipsis,
+    Paginator, PaginationState, PageItem, page_window, use_pagination,
     Panel, PanelHeading, PanelTabs, PanelBlock, PanelIcon,
     Tabs, Tab
 };
\ No newline at end of file
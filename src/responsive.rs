@@ -0,0 +1,272 @@
+//! Per-breakpoint values for layout props.
+//!
+//! Bulma's responsive modifiers append a breakpoint suffix to a class
+//! (`is-half` → `is-half-tablet`, `is-hidden` → `is-hidden-mobile`). A
+//! [`Responsive<T>`] holds a base value plus any number of per-[`Breakpoint`]
+//! overrides and renders them into those classes.
+//!
+//! Plain values convert into `Responsive<T>`, so existing props keep working:
+//!
+//! ```rust,ignore
+//! Column { size: ColumnSize::Half, "Same size everywhere" }
+//! Column {
+//!     size: Responsive::new(ColumnSize::Full)
+//!         .tablet(ColumnSize::Half)
+//!         .desktop(ColumnSize::OneThird),
+//!     offset: Responsive::default().widescreen(ColumnSize::Two),
+//!     hidden: Responsive::default().mobile(true),
+//!     "Responsive column"
+//! }
+//! ```
+
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::{impl_name_display, named_enum};
+
+/// Bulma's responsive breakpoints, used as class suffixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Breakpoint {
+    /// Up to 768px.
+    Mobile,
+    /// From 769px.
+    Tablet,
+    /// Up to 1023px (mobile and tablet).
+    Touch,
+    /// From 1024px.
+    Desktop,
+    /// From 1216px.
+    Widescreen,
+    /// From 1408px.
+    FullHD,
+}
+
+impl Breakpoint {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Breakpoint::Mobile => "mobile",
+            Breakpoint::Tablet => "tablet",
+            Breakpoint::Touch => "touch",
+            Breakpoint::Desktop => "desktop",
+            Breakpoint::Widescreen => "widescreen",
+            Breakpoint::FullHD => "fullhd",
+        }
+    }
+}
+
+named_enum!(Breakpoint {
+    Mobile => "mobile",
+    Tablet => "tablet",
+    Touch => "touch",
+    Desktop => "desktop",
+    Widescreen => "widescreen",
+    FullHD => "fullhd",
+});
+
+impl_name_display!(Breakpoint);
+
+/// Text alignment, rendered as `has-text-<alignment>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextAlignment {
+    Centered,
+    Justified,
+    Left,
+    Right,
+}
+
+impl TextAlignment {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TextAlignment::Centered => "has-text-centered",
+            TextAlignment::Justified => "has-text-justified",
+            TextAlignment::Left => "has-text-left",
+            TextAlignment::Right => "has-text-right",
+        }
+    }
+}
+
+impl BulmaClass for TextAlignment {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("has-text-").unwrap_or_default()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "has-text-"
+    }
+}
+
+impl_class_display!(TextAlignment);
+
+named_enum!(TextAlignment {
+    Centered => "centered",
+    Justified => "justified",
+    Left => "left",
+    Right => "right",
+});
+
+/// A value that may differ per [`Breakpoint`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Responsive<T> {
+    base: Option<T>,
+    overrides: Vec<(Breakpoint, T)>,
+}
+
+impl<T> Default for Responsive<T> {
+    /// No base value and no overrides; renders no classes.
+    fn default() -> Self {
+        Self { base: None, overrides: Vec::new() }
+    }
+}
+
+impl<T> From<T> for Responsive<T> {
+    fn from(base: T) -> Self {
+        Self::new(base)
+    }
+}
+
+impl<T> Responsive<T> {
+    /// A value applied at every breakpoint, unless overridden.
+    pub fn new(base: T) -> Self {
+        Self { base: Some(base), overrides: Vec::new() }
+    }
+
+    /// Sets the value used from `breakpoint` on, replacing any previous
+    /// value for it.
+    pub fn at(mut self, breakpoint: Breakpoint, value: T) -> Self {
+        self.overrides.retain(|(existing, _)| *existing != breakpoint);
+        self.overrides.push((breakpoint, value));
+        self
+    }
+
+    pub fn mobile(self, value: T) -> Self {
+        self.at(Breakpoint::Mobile, value)
+    }
+
+    pub fn tablet(self, value: T) -> Self {
+        self.at(Breakpoint::Tablet, value)
+    }
+
+    pub fn touch(self, value: T) -> Self {
+        self.at(Breakpoint::Touch, value)
+    }
+
+    pub fn desktop(self, value: T) -> Self {
+        self.at(Breakpoint::Desktop, value)
+    }
+
+    pub fn widescreen(self, value: T) -> Self {
+        self.at(Breakpoint::Widescreen, value)
+    }
+
+    pub fn fullhd(self, value: T) -> Self {
+        self.at(Breakpoint::FullHD, value)
+    }
+
+    /// The value applied at every breakpoint, if any.
+    pub fn base(&self) -> Option<&T> {
+        self.base.as_ref()
+    }
+
+    /// The override for `breakpoint`, if any.
+    pub fn get(&self, breakpoint: Breakpoint) -> Option<&T> {
+        self.overrides
+            .iter()
+            .find(|(existing, _)| *existing == breakpoint)
+            .map(|(_, value)| value)
+    }
+
+    /// The per-breakpoint overrides, in the order they were set.
+    pub fn overrides(&self) -> &[(Breakpoint, T)] {
+        &self.overrides
+    }
+
+    /// Returns `true` if neither a base value nor overrides are set.
+    pub fn is_empty(&self) -> bool {
+        self.base.is_none() && self.overrides.is_empty()
+    }
+
+    /// Renders the classes for every value: `class(value)` for the base value
+    /// and `class(value)-<breakpoint>` for each override. Values mapped to an
+    /// empty class are skipped. Returns `None` if no class was produced.
+    pub fn to_class<C: AsRef<str>>(&self, class: impl Fn(&T) -> C) -> Option<String> {
+        let mut classes = Vec::new();
+        if let Some(base) = &self.base {
+            let base = class(base);
+            if !base.as_ref().is_empty() {
+                classes.push(base.as_ref().to_string());
+            }
+        }
+        for (breakpoint, value) in &self.overrides {
+            let value = class(value);
+            if !value.as_ref().is_empty() {
+                classes.push(format!("{}-{}", value.as_ref(), breakpoint.as_str()));
+            }
+        }
+        if classes.is_empty() {
+            None
+        } else {
+            Some(classes.join(" "))
+        }
+    }
+}
+
+impl Responsive<bool> {
+    /// Renders Bulma's visibility classes hiding the element wherever the
+    /// value is `true`.
+    ///
+    /// Each override covers the widths of the matching `is-hidden-<breakpoint>`
+    /// class (`tablet` means tablet and up, `touch` mobile and tablet) and
+    /// overrides are applied in breakpoint order, so `false` can show an
+    /// element again: `Responsive::new(true).desktop(false)` renders
+    /// `is-hidden-touch`.
+    pub fn to_hidden_class(&self) -> Option<String> {
+        // Mobile, tablet, desktop, widescreen and fullhd widths.
+        let mut hidden = [self.base.unwrap_or(false); 5];
+        for breakpoint in Breakpoint::ALL {
+            if let Some(&value) = self.get(breakpoint) {
+                let widths = match breakpoint {
+                    Breakpoint::Mobile => 0..1,
+                    Breakpoint::Tablet => 1..5,
+                    Breakpoint::Touch => 0..2,
+                    Breakpoint::Desktop => 2..5,
+                    Breakpoint::Widescreen => 3..5,
+                    Breakpoint::FullHD => 4..5,
+                };
+                hidden[widths].fill(value);
+            }
+        }
+
+        if hidden.iter().all(|&hidden| hidden) {
+            return Some("is-hidden".to_string());
+        }
+        // Hidden from this width up, covered by a single `is-hidden-<breakpoint>`.
+        let from = hidden.iter().rposition(|&hidden| !hidden).map_or(0, |shown| shown + 1);
+        let mut classes = Vec::new();
+        if hidden[0] && hidden[1] && from > 1 {
+            classes.push("is-hidden-touch");
+        } else {
+            classes.extend(hidden[0].then_some("is-hidden-mobile"));
+            classes.extend((hidden[1] && from > 1).then_some("is-hidden-tablet-only"));
+        }
+        classes.extend((hidden[2] && from > 2).then_some("is-hidden-desktop-only"));
+        classes.extend((hidden[3] && from > 3).then_some("is-hidden-widescreen-only"));
+        classes.extend(match from {
+            1 => Some("is-hidden-tablet"),
+            2 => Some("is-hidden-desktop"),
+            3 => Some("is-hidden-widescreen"),
+            4 => Some("is-hidden-fullhd"),
+            _ => None,
+        });
+
+        if classes.is_empty() {
+            None
+        } else {
+            Some(classes.join(" "))
+        }
+    }
+}
+
+impl Responsive<TextAlignment> {
+    /// Renders `has-text-<alignment>` classes.
+    pub fn to_text_align_class(&self) -> Option<String> {
+        self.to_class(TextAlignment::as_class)
+    }
+}
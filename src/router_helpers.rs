@@ -9,6 +9,9 @@
 //!
 //! Addresses [issue #6](https://github.com/rexlunae/dioxus-bulma/issues/6).
 //!
+//! [`use_query_state`] keeps a value in the URL's query string, so pages,
+//! tabs and search terms survive reloads and can be shared as links.
+//!
 //! # Example
 //!
 //! ```rust,ignore
@@ -29,12 +32,16 @@
 //! }
 //! ```
 
-use dioxus::prelude::NavigationTarget;
+use dioxus::prelude::*;
+use dioxus::router::RouterContext;
+use std::fmt;
+use std::str::FromStr;
+use std::rc::Rc;
 
 /// Optional [`NavigationTarget`] wrapper that allows ergonomic conversion from
 /// any `T: Into<NavigationTarget>` (including `Routable` enum variants) via
 /// `#[props(into)]` on a Dioxus component prop.
-#[derive(Clone, PartialEq, Debug)]
+#[derive(Clone, PartialEq, Debug, Default)]
 pub struct MaybeNav(pub Option<NavigationTarget>);
 
 impl MaybeNav {
@@ -59,12 +66,6 @@ impl MaybeNav {
     }
 }
 
-impl Default for MaybeNav {
-    fn default() -> Self {
-        Self(None)
-    }
-}
-
 // Blanket conversion: anything that already converts into `NavigationTarget`
 // — including `NavigationTarget` itself (via the standard reflexive
 // `Into<T> for T`) and any `Routable` route — converts into `MaybeNav` as
@@ -74,3 +75,294 @@ impl<T: Into<NavigationTarget>> From<T> for MaybeNav {
         Self(Some(value.into()))
     }
 }
+
+/// How a link's `to` target is compared with the current route to decide
+/// whether the link is active.
+///
+/// Components with both `to` and `active` props accept an `active_match`; when
+/// `active` is unset they render `is-active` and `aria-current="page"` while
+/// the route matches:
+///
+/// ```rust,ignore
+/// MenuItem { to: Route::Home {}, active_match: ActiveMatch::Exact, "Home" }
+/// // Also active on /devices/42:
+/// MenuItem { to: Route::DeviceList {}, active_match: ActiveMatch::Prefix, "Devices" }
+/// MenuItem {
+///     to: Route::Settings {},
+///     active_match: ActiveMatch::custom(|path| path.starts_with("/settings") || path == "/profile"),
+///     "Settings"
+/// }
+/// ```
+#[derive(Clone)]
+pub enum ActiveMatch {
+    /// The current path equals the target's path.
+    Exact,
+    /// The current path is the target's path or lies below it, segment-wise:
+    /// `/docs` matches `/docs` and `/docs/intro`, not `/docs-old`.
+    Prefix,
+    /// A predicate over the current path.
+    Custom(Rc<dyn Fn(&str) -> bool>),
+}
+
+impl ActiveMatch {
+    pub fn custom(predicate: impl Fn(&str) -> bool + 'static) -> Self {
+        Self::Custom(Rc::new(predicate))
+    }
+
+    /// Returns `true` if a link to `target` is active at `current`. Query
+    /// strings and fragments are ignored, as are trailing slashes. External
+    /// targets are never active unless a custom predicate says so.
+    pub fn is_active(&self, target: Option<&NavigationTarget>, current: &str) -> bool {
+        let current = route_path(current);
+        let target = match target {
+            Some(NavigationTarget::Internal(path)) => route_path(path),
+            _ => None,
+        };
+        match (self, current, target) {
+            (ActiveMatch::Custom(predicate), Some(current), _) => predicate(current),
+            (ActiveMatch::Exact, Some(current), Some(target)) => current == target,
+            (ActiveMatch::Prefix, Some(current), Some(target)) => {
+                target == "/"
+                    || current
+                        .strip_prefix(target)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether a link to `target` is active at the router's current route.
+    /// Subscribes the calling component to route changes; `false` outside of
+    /// a router.
+    pub fn matches(&self, target: &MaybeNav) -> bool {
+        dioxus::router::try_router()
+            .is_some_and(|router| self.is_active(target.as_target(), &router.full_route_string()))
+    }
+}
+
+/// The path of a route string, without query, fragment or trailing slash.
+fn route_path(route: &str) -> Option<&str> {
+    let path = route.split(['?', '#']).next()?;
+    match path.trim_end_matches('/') {
+        "" => Some("/"),
+        path => Some(path),
+    }
+}
+
+impl PartialEq for ActiveMatch {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (ActiveMatch::Exact, ActiveMatch::Exact) | (ActiveMatch::Prefix, ActiveMatch::Prefix) => true,
+            (ActiveMatch::Custom(a), ActiveMatch::Custom(b)) => Rc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Debug for ActiveMatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ActiveMatch::Exact => f.write_str("Exact"),
+            ActiveMatch::Prefix => f.write_str("Prefix"),
+            ActiveMatch::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// Resolves a component's `active` prop, falling back to its `active_match`
+/// against the current route.
+pub(crate) fn resolve_active(active: Option<bool>, active_match: Option<&ActiveMatch>, target: &MaybeNav) -> bool {
+    active.unwrap_or_else(|| active_match.is_some_and(|active_match| active_match.matches(target)))
+}
+
+/// A value kept in one parameter of the URL's query string, returned by
+/// [`use_query_state`].
+///
+/// The handle is `Copy`, so it can be moved into event handlers freely.
+#[derive(PartialEq)]
+pub struct QueryState<T: 'static> {
+    value: Signal<T>,
+    key: CopyValue<String>,
+    default: CopyValue<T>,
+    router: CopyValue<Option<RouterContext>>,
+}
+
+// Derived impls would require `T: Copy`; the handles are `Copy` for any `T`.
+impl<T> Clone for QueryState<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for QueryState<T> {}
+
+impl<T: FromStr + fmt::Display + Clone + PartialEq + 'static> QueryState<T> {
+    /// Returns the current value, subscribing the caller to changes.
+    pub fn get(&self) -> T {
+        self.value.cloned()
+    }
+
+    /// Stores `value` and writes it to the query string, replacing the
+    /// current history entry. The default value removes the parameter.
+    pub fn set(&mut self, value: T) {
+        if *self.value.peek() != value {
+            self.value.set(value.clone());
+        }
+
+        let Some(router) = *self.router.peek() else {
+            return;
+        };
+        let current = router.full_route_string();
+        let param = (value != *self.default.peek()).then(|| value.to_string());
+        let url = with_query_param(&current, &self.key.peek(), param.as_deref());
+        if url != current {
+            router.replace(NavigationTarget::Internal(url));
+        }
+    }
+
+    /// Resets the value to its default and removes the parameter.
+    pub fn reset(&mut self) {
+        self.set(self.default.cloned());
+    }
+
+    /// The name of the query parameter.
+    pub fn key(&self) -> String {
+        self.key.cloned()
+    }
+
+    /// Returns the underlying signal, for use with `use_memo` and friends.
+    pub fn signal(&self) -> Signal<T> {
+        self.value
+    }
+}
+
+/// Binds a value to the `key` parameter of the URL's query string.
+///
+/// The value is read from the query string on first render and follows it
+/// when the route changes, e.g. on back and forward navigation; a missing or
+/// unparsable parameter gives `default`. [`QueryState::set`] writes the value
+/// back. Outside of a router the value is kept in memory only.
+///
+/// The router rewrites the URL to the parsed route when it starts, so the
+/// route has to capture its query string, e.g.
+/// `#[route("/devices?:..query")] Devices { query: String }`.
+///
+/// ```rust,ignore
+/// let mut sort = use_query_state("sort", SortOrder::Newest);
+/// rsx! {
+///     Button { onclick: move |_| sort.set(SortOrder::Oldest), "Oldest first" }
+///     DeviceList { sort: sort.get() }
+/// }
+/// ```
+pub fn use_query_state<T>(key: impl Into<String>, default: T) -> QueryState<T>
+where
+    T: FromStr + fmt::Display + Clone + PartialEq + 'static,
+{
+    let key = use_hook(|| CopyValue::new(key.into()));
+    let default = use_hook(|| CopyValue::new(default));
+    let router = use_hook(|| CopyValue::new(dioxus::router::try_router()));
+    let read = move |url: &str| {
+        query_param(url, &key.peek())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_else(|| default.cloned())
+    };
+    let mut value = use_signal(|| match *router.peek() {
+        Some(router) => read(&router.full_route_string()),
+        None => default.cloned(),
+    });
+
+    use_effect(move || {
+        if key.peek().is_empty() {
+            return;
+        }
+        if let Some(router) = *router.peek() {
+            let current = read(&router.full_route_string());
+            if *value.peek() != current {
+                value.set(current);
+            }
+        }
+    });
+
+    QueryState { value, key, default, router }
+}
+
+/// [`use_query_state`] for components with an optional `query_key` prop:
+/// `None` when no key is given.
+pub(crate) fn use_query_binding<T>(key: Option<String>, default: T) -> Option<QueryState<T>>
+where
+    T: FromStr + fmt::Display + Clone + PartialEq + 'static,
+{
+    let state = use_query_state(key.clone().unwrap_or_default(), default);
+    key.map(|_| state)
+}
+
+/// The decoded value of `key` in `url`'s query string.
+fn query_param(url: &str, key: &str) -> Option<String> {
+    let query = url.split('#').next()?.split_once('?')?.1;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('=').or(Some((pair, ""))))
+        .find(|(name, _)| decode_query_component(name) == key)
+        .map(|(_, value)| decode_query_component(value))
+}
+
+/// `url` with `key` set to `value` in its query string, or removed when
+/// `value` is `None`. Other parameters and the fragment are kept.
+fn with_query_param(url: &str, key: &str, value: Option<&str>) -> String {
+    let (url, fragment) = url.split_once('#').map_or((url, None), |(url, fragment)| (url, Some(fragment)));
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+
+    let mut pairs: Vec<String> = query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .filter(|pair| decode_query_component(pair.split('=').next().unwrap_or_default()) != key)
+        .map(str::to_string)
+        .collect();
+    if let Some(value) = value {
+        pairs.push(format!("{}={}", encode_query_component(key), encode_query_component(value)));
+    }
+
+    let mut url = path.to_string();
+    if !pairs.is_empty() {
+        url.push('?');
+        url.push_str(&pairs.join("&"));
+    }
+    if let Some(fragment) = fragment {
+        url.push('#');
+        url.push_str(fragment);
+    }
+    url
+}
+
+fn encode_query_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => encoded.push(byte as char),
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+fn decode_query_component(value: &str) -> String {
+    let mut bytes = Vec::with_capacity(value.len());
+    let mut rest = value.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' if tail.len() >= 2
+                && let Some(decoded) = std::str::from_utf8(&tail[..2])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok()) =>
+            {
+                bytes.push(decoded);
+                rest = &tail[2..];
+                continue;
+            }
+            _ => bytes.push(byte),
+        }
+        rest = tail;
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
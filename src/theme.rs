@@ -1,20 +1,52 @@
+use crate::defaults::BulmaDefaults;
+use crate::modifier::{impl_class_display, BulmaClass};
+use crate::enums::{impl_from_str, impl_name_display, named_enum, BulmaEnum};
 use dioxus::prelude::*;
+use std::rc::Rc;
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum BulmaTheme {
+    #[default]
     Auto,
     Light,
     Dark,
 }
 
-impl Default for BulmaTheme {
-    fn default() -> Self {
-        BulmaTheme::Auto
+impl BulmaTheme {
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            BulmaTheme::Auto => "theme-auto",
+            BulmaTheme::Light => "theme-light",
+            BulmaTheme::Dark => "theme-dark",
+        }
+    }
+
+    /// Resolves `Auto` to `Dark` or `Light` according to the OS color scheme;
+    /// explicit themes are returned unchanged.
+    pub fn resolve(self, prefers_dark: bool) -> BulmaTheme {
+        match self {
+            BulmaTheme::Auto if prefers_dark => BulmaTheme::Dark,
+            BulmaTheme::Auto => BulmaTheme::Light,
+            theme => theme,
+        }
+    }
+}
+
+impl BulmaClass for BulmaTheme {
+    fn name(&self) -> &'static str {
+        self.variant_name()
+    }
+
+    fn prefix(&self) -> &'static str {
+        "theme-"
     }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum BulmaColor {
+    /// A color registered with [`BulmaThemeConfig::custom_colors`], rendered
+    /// as `is-<name>` like the built-in colors.
+    Custom(&'static str),
     White,
     Light,
     Dark,
@@ -29,23 +61,19 @@ pub enum BulmaColor {
     Danger,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum BulmaSize {
     Small,
+    #[default]
     Normal,
     Medium,
     Large,
 }
 
-impl Default for BulmaSize {
-    fn default() -> Self {
-        BulmaSize::Normal
-    }
-}
-
 impl BulmaColor {
     pub fn as_str(&self) -> &'static str {
         match self {
+            BulmaColor::Custom(name) => name,
             BulmaColor::White => "white",
             BulmaColor::Light => "light",
             BulmaColor::Dark => "dark",
@@ -62,6 +90,132 @@ impl BulmaColor {
     }
 }
 
+impl BulmaClass for BulmaColor {
+    fn name(&self) -> &'static str {
+        self.as_str()
+    }
+}
+
+/// A lighter or darker variant of a [`BulmaColor`], accepted by the `shade`
+/// prop of every component that is filled with or draws text in its `color`.
+///
+/// ```rust,ignore
+/// Button { color: BulmaColor::Primary, shade: ColorShade::Light, "Soft" }
+/// Tag { color: BulmaColor::Info, shade: ColorShade::Step(90), "Pale" }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColorShade {
+    Light,
+    Dark,
+    /// One of Bulma's palette steps, from `0` (darkest) to `100` (lightest).
+    /// Values are rounded down to a multiple of 5 and capped at 100.
+    Step(u8),
+}
+
+impl ColorShade {
+    /// The two-digit palette suffix Bulma uses for [`ColorShade::Step`],
+    /// e.g. `"05"` or `"90"`.
+    pub fn step_suffix(&self) -> Option<String> {
+        match self {
+            ColorShade::Step(step) => Some(format!("{:02}", (*step).min(100) / 5 * 5)),
+            _ => None,
+        }
+    }
+
+    /// Classes for a component filled with `color` that has Bulma's own
+    /// light/dark variants (buttons, tags and notifications), e.g.
+    /// `is-light` or `has-background-primary-90 has-text-primary-90-invert`.
+    pub fn fill_class(&self, color: BulmaColor) -> String {
+        match self.step_suffix() {
+            Some(_) => self.background_class(color),
+            None => self.modifier().to_string(),
+        }
+    }
+
+    /// Classes for a component filled with `color` that Bulma has no
+    /// light/dark variants for, where `is-light`/`is-dark` would be colors of
+    /// their own. `Light` and `Dark` pair the shaded background with the
+    /// opposite shade for the text, e.g.
+    /// `has-background-primary-light has-text-primary-dark`.
+    pub fn background_class(&self, color: BulmaColor) -> String {
+        let color = color.as_str();
+        match self {
+            ColorShade::Light => format!("has-background-{color}-light has-text-{color}-dark"),
+            ColorShade::Dark => format!("has-background-{color}-dark has-text-{color}-light"),
+            ColorShade::Step(_) => {
+                let step = self.step_suffix().unwrap_or_default();
+                format!("has-background-{color}-{step} has-text-{color}-{step}-invert")
+            }
+        }
+    }
+
+    /// Class for a component that draws text in `color`, e.g.
+    /// `has-text-primary-dark` or `has-text-primary-40`.
+    pub fn text_class(&self, color: BulmaColor) -> String {
+        let suffix = self.step_suffix().unwrap_or_else(|| self.modifier().trim_start_matches("is-").to_string());
+        format!("has-text-{}-{}", color.as_str(), suffix)
+    }
+
+    /// CSS color value of this variant of `color`, e.g.
+    /// `hsl(var(--bulma-primary-h), var(--bulma-primary-s), var(--bulma-primary-90-l))`.
+    /// Used inline by components Bulma has no shaded modifiers for, such as
+    /// form controls and progress bars.
+    pub fn color_value(&self, color: BulmaColor) -> String {
+        let suffix = self.step_suffix().unwrap_or_else(|| self.modifier().trim_start_matches("is-").to_string());
+        format!(
+            "hsl(var(--bulma-{color}-h), var(--bulma-{color}-s), var(--bulma-{color}-{suffix}-l))",
+            color = color.as_str()
+        )
+    }
+
+    fn modifier(&self) -> &'static str {
+        match self {
+            ColorShade::Light => "is-light",
+            ColorShade::Dark => "is-dark",
+            ColorShade::Step(_) => "",
+        }
+    }
+}
+
+/// Names of the palette steps, indexed by `step / 5`.
+const SHADE_STEPS: [&str; 21] = [
+    "00", "05", "10", "15", "20", "25", "30", "35", "40", "45", "50", "55", "60", "65", "70", "75", "80", "85",
+    "90", "95", "100",
+];
+
+impl ColorShade {
+    /// `Light`, `Dark` and every palette step from `0` to `100`.
+    pub const ALL: [ColorShade; 23] = {
+        let mut all = [ColorShade::Light; 23];
+        all[1] = ColorShade::Dark;
+        let mut step = 0;
+        while step < SHADE_STEPS.len() {
+            all[step + 2] = ColorShade::Step(step as u8 * 5);
+            step += 1;
+        }
+        all
+    };
+}
+
+/// Shades are named `light`, `dark` or by their two-digit palette step
+/// (`"05"`, `"90"`).
+impl BulmaEnum for ColorShade {
+    fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    fn variant_name(&self) -> &'static str {
+        match self {
+            ColorShade::Light => "light",
+            ColorShade::Dark => "dark",
+            ColorShade::Step(step) => SHADE_STEPS[usize::from((*step).min(100) / 5)],
+        }
+    }
+}
+
+impl_from_str!(ColorShade);
+impl_name_display!(ColorShade);
+
 impl BulmaSize {
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -71,47 +225,698 @@ impl BulmaSize {
             BulmaSize::Large => "large",
         }
     }
-    
+
     pub fn as_class(&self) -> &'static str {
         match self {
             BulmaSize::Small => "is-small",
             BulmaSize::Normal => "",
-            BulmaSize::Medium => "is-medium", 
+            BulmaSize::Medium => "is-medium",
             BulmaSize::Large => "is-large",
         }
     }
 }
 
+impl BulmaClass for BulmaSize {
+    fn name(&self) -> &'static str {
+        self.as_class().strip_prefix("is-").unwrap_or_default()
+    }
+}
+
+impl_class_display!(BulmaTheme, BulmaColor, BulmaSize);
+
+named_enum!(BulmaTheme {
+    Auto => "auto",
+    Light => "light",
+    Dark => "dark",
+});
+
+named_enum!(BulmaColor {
+    White => "white",
+    Light => "light",
+    Dark => "dark",
+    Black => "black",
+    Text => "text",
+    Ghost => "ghost",
+    Primary => "primary",
+    Link => "link",
+    Info => "info",
+    Success => "success",
+    Warning => "warning",
+    Danger => "danger",
+}, BulmaColor::Custom(name) => name);
+
+named_enum!(BulmaSize {
+    Small => "small",
+    Normal => "normal",
+    Medium => "medium",
+    Large => "large",
+});
+
+/// Bulma version bundled with the crate and loaded by
+/// [`BulmaCssSource::default`].
+pub const BULMA_VERSION: &str = "1.0.0";
+
+/// The copy of Bulma shipped in the crate's `assets/` directory.
+const BUNDLED_BULMA: Asset = asset!("/assets/bulma.min.css");
+
+/// Where [`BulmaProvider`] loads Bulma's stylesheet from.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub enum BulmaCssSource {
+    /// The copy of Bulma [`BULMA_VERSION`] shipped with this crate, served
+    /// from the app's own assets so desktop and offline builds need no
+    /// network access.
+    #[default]
+    Bundled,
+    /// The jsDelivr CDN, pinned to the given Bulma version (e.g. `"1.0.4"`).
+    Cdn(String),
+    /// A stylesheet from the app's own assets, e.g. a custom Bulma build:
+    /// `asset!("/assets/bulma.min.css")`.
+    Asset(Asset),
+    /// Any other URL, such as an intranet mirror.
+    Url(String),
+    /// Raw CSS injected into a `<style>` element.
+    Inline(String),
+}
+
+impl BulmaCssSource {
+    /// Returns the URL of the stylesheet, or `None` for inline CSS.
+    pub fn href(&self) -> Option<String> {
+        match self {
+            BulmaCssSource::Bundled => Some(BUNDLED_BULMA.to_string()),
+            BulmaCssSource::Cdn(version) => Some(format!(
+                "https://cdn.jsdelivr.net/npm/bulma@{version}/css/bulma.min.css"
+            )),
+            BulmaCssSource::Asset(asset) => Some(asset.to_string()),
+            BulmaCssSource::Url(url) => Some(url.clone()),
+            BulmaCssSource::Inline(_) => None,
+        }
+    }
+}
+
+/// A color expressed as the hue/saturation/lightness triple Bulma 1.0 derives
+/// its palette from. Saturation and lightness fall back to Bulma's defaults
+/// when unset.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorHsl {
+    /// Hue in degrees (`0.0..360.0`).
+    pub hue: f32,
+    /// Saturation in percent.
+    pub saturation: Option<f32>,
+    /// Lightness in percent.
+    pub lightness: Option<f32>,
+}
+
+impl ColorHsl {
+    pub fn new(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self { hue, saturation: Some(saturation), lightness: Some(lightness) }
+    }
+
+    /// Overrides only the hue, keeping Bulma's saturation and lightness.
+    pub fn hue(hue: f32) -> Self {
+        Self { hue, saturation: None, lightness: None }
+    }
+
+    fn push_css_vars(&self, name: &str, css: &mut String) {
+        css.push_str(&format!("--bulma-{name}-h: {}deg; ", self.hue));
+        if let Some(saturation) = self.saturation {
+            css.push_str(&format!("--bulma-{name}-s: {saturation}%; "));
+        }
+        if let Some(lightness) = self.lightness {
+            css.push_str(&format!("--bulma-{name}-l: {lightness}%; "));
+        }
+    }
+}
+
+/// A brand color beyond Bulma's built-in palette, registered through
+/// [`BulmaThemeConfig::custom_colors`].
+///
+/// The provider generates the `--bulma-<name>-*` variables and the
+/// `is-<name>` modifier rules for every colored component, so
+/// [`BulmaColor::Custom`] behaves like the built-in colors.
+///
+/// ```rust,ignore
+/// const BRAND: CustomColor = CustomColor::new("brand", 330.0, 80.0, 50.0);
+///
+/// rsx! {
+///     BulmaProvider {
+///         config: BulmaThemeConfig { custom_colors: vec![BRAND], ..Default::default() },
+///         Button { color: BRAND.color(), "Buy now" }
+///     }
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CustomColor {
+    /// Class-name suffix, e.g. `"brand"` for `is-brand`.
+    pub name: &'static str,
+    /// Hue in degrees (`0.0..360.0`).
+    pub hue: f32,
+    /// Saturation in percent.
+    pub saturation: f32,
+    /// Lightness in percent.
+    pub lightness: f32,
+}
+
+impl CustomColor {
+    pub const fn new(name: &'static str, hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self { name, hue, saturation, lightness }
+    }
+
+    /// The [`BulmaColor`] to pass to a component's `color` prop.
+    pub const fn color(&self) -> BulmaColor {
+        BulmaColor::Custom(self.name)
+    }
+
+    /// Lightness of the text drawn on top of this color: dark text on light
+    /// colors, white text on dark ones.
+    fn invert_lightness(&self) -> f32 {
+        if self.lightness > 60.0 { 4.0 } else { 100.0 }
+    }
+
+    /// Returns the variable declarations (for a `:root` rule) defining this
+    /// color, mirroring the ones Bulma generates for its own palette.
+    pub fn to_css_vars(&self) -> String {
+        let Self { name, hue, saturation, lightness } = *self;
+        let invert = self.invert_lightness();
+        format!(
+            "--bulma-{name}-h: {hue}deg; --bulma-{name}-s: {saturation}%; --bulma-{name}-l: {lightness}%; \
+             --bulma-{name}-invert-l: {invert}%; \
+             --bulma-{name}: hsl({hue}deg, {saturation}%, {lightness}%); \
+             --bulma-{name}-invert: hsl({hue}deg, {saturation}%, {invert}%); "
+        )
+    }
+
+    /// Returns the `is-<name>` modifier rules for Bulma's colored components.
+    pub fn to_css_rules(&self) -> String {
+        let name = self.name;
+        let color = format!("var(--bulma-{name})");
+        let invert = format!("var(--bulma-{name}-invert)");
+        let hover = format!(
+            "hsl(var(--bulma-{name}-h), var(--bulma-{name}-s), calc(var(--bulma-{name}-l) - 5%))"
+        );
+        [
+            format!(".button.is-{name} {{ background-color: {color}; border-color: transparent; color: {invert}; }}"),
+            format!(".button.is-{name}:hover, .button.is-{name}.is-hovered {{ background-color: {hover}; color: {invert}; }}"),
+            format!(".button.is-{name}.is-outlined {{ background-color: transparent; border-color: {color}; color: {color}; }}"),
+            format!(".button.is-{name}.is-outlined:hover {{ background-color: {color}; color: {invert}; }}"),
+            format!(".button.is-{name}.is-inverted {{ background-color: {invert}; color: {color}; }}"),
+            format!(".tag.is-{name}, .notification.is-{name}, .navbar.is-{name}, .hero.is-{name} {{ background-color: {color}; color: {invert}; }}"),
+            format!(".hero.is-{name} .title, .hero.is-{name} .subtitle, .navbar.is-{name} .navbar-item {{ color: {invert}; }}"),
+            format!(".message.is-{name} .message-header, .panel.is-{name} .panel-heading {{ background-color: {color}; color: {invert}; }}"),
+            format!(".message.is-{name} .message-body {{ border-color: {color}; }}"),
+            format!(".input.is-{name}, .textarea.is-{name}, .select.is-{name} select, .select.is-{name}:not(.is-multiple):not(.is-loading)::after {{ border-color: {color}; }}"),
+            format!(".input.is-{name}:focus, .textarea.is-{name}:focus, .select.is-{name} select:focus {{ box-shadow: 0 0 0 0.125em hsla(var(--bulma-{name}-h), var(--bulma-{name}-s), var(--bulma-{name}-l), 0.25); }}"),
+            format!(".file.is-{name} .file-cta {{ background-color: {color}; color: {invert}; }}"),
+            // Vendor pseudo-elements go in separate rules: a browser drops a
+            // whole rule when one of its selectors is unknown to it.
+            format!(".progress.is-{name}::-webkit-progress-value {{ background-color: {color}; }}"),
+            format!(".progress.is-{name}::-moz-progress-bar {{ background-color: {color}; }}"),
+            format!(".help.is-{name}, .has-text-{name} {{ color: {color} !important; }}"),
+            format!(".has-background-{name} {{ background-color: {color} !important; }}"),
+        ]
+        .join("\n")
+    }
+}
+
+/// Branding for Bulma 1.0, expressed as `--bulma-*` CSS custom properties.
+///
+/// Every field is optional; unset fields keep Bulma's defaults. Pass it to
+/// the `config` prop of [`BulmaProvider`] to brand an app from Rust without a
+/// Sass toolchain.
+///
+/// ```rust,ignore
+/// let config = BulmaThemeConfig {
+///     primary: Some(ColorHsl::new(265.0, 70.0, 55.0)),
+///     radius: Some("0.25rem".into()),
+///     font_family: Some("\"Inter\", sans-serif".into()),
+///     ..Default::default()
+/// };
+///
+/// rsx! { BulmaProvider { config: config, App {} } }
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BulmaThemeConfig {
+    pub primary: Option<ColorHsl>,
+    pub link: Option<ColorHsl>,
+    pub info: Option<ColorHsl>,
+    pub success: Option<ColorHsl>,
+    pub warning: Option<ColorHsl>,
+    pub danger: Option<ColorHsl>,
+    /// Default border radius (`--bulma-radius`), e.g. `"0.5rem"`.
+    pub radius: Option<String>,
+    /// Primary font stack (`--bulma-family-primary`).
+    pub font_family: Option<String>,
+    /// Vertical space between blocks (`--bulma-block-spacing`).
+    pub block_spacing: Option<String>,
+    /// Gap between columns (`--bulma-column-gap`).
+    pub column_gap: Option<String>,
+    /// Extra named colors usable through [`BulmaColor::Custom`].
+    pub custom_colors: Vec<CustomColor>,
+}
+
+impl BulmaThemeConfig {
+    /// Returns the CSS custom property declarations for the configured
+    /// fields, e.g. `--bulma-primary-h: 265deg; --bulma-radius: 0.25rem; `.
+    pub fn to_css_vars(&self) -> String {
+        let mut css = String::new();
+        let colors = [
+            ("primary", self.primary),
+            ("link", self.link),
+            ("info", self.info),
+            ("success", self.success),
+            ("warning", self.warning),
+            ("danger", self.danger),
+        ];
+        for (name, color) in colors.iter().filter_map(|(name, color)| Some((name, (*color)?))) {
+            color.push_css_vars(name, &mut css);
+        }
+        let values = [
+            ("radius", &self.radius),
+            ("family-primary", &self.font_family),
+            ("block-spacing", &self.block_spacing),
+            ("column-gap", &self.column_gap),
+        ];
+        for (name, value) in values.iter().filter_map(|(name, value)| Some((name, value.as_ref()?))) {
+            css.push_str(&format!("--bulma-{name}: {value}; "));
+        }
+        for custom in &self.custom_colors {
+            css.push_str(&custom.to_css_vars());
+        }
+        css
+    }
+
+    /// Wraps [`BulmaThemeConfig::to_css_vars`] in a rule for `selector`,
+    /// followed by the modifier rules of any custom colors.
+    pub fn to_stylesheet(&self, selector: &str) -> String {
+        let mut css = format!("{selector} {{ {}}}", self.to_css_vars());
+        for custom in &self.custom_colors {
+            css.push('\n');
+            css.push_str(&custom.to_css_rules());
+        }
+        css
+    }
+}
+
+/// Persists the user's chosen [`BulmaTheme`] so that [`BulmaProvider`] can
+/// restore it on startup.
+///
+/// Implement this for whatever storage your platform offers (a config file
+/// on desktop, a cookie on the server, ...). On the web, enable the `web`
+/// feature and use [`LocalStorage`].
+pub trait ThemeStorage {
+    /// Returns the previously saved theme, if any.
+    fn load(&self) -> Option<BulmaTheme>;
+    /// Saves `theme` so it is returned by the next call to [`ThemeStorage::load`].
+    fn save(&self, theme: BulmaTheme);
+}
+
+/// Optional handle to a [`ThemeStorage`] implementation, accepted by the
+/// `storage` prop of [`BulmaProvider`].
+///
+/// Like `MaybeNav`, any `ThemeStorage` converts into this
+/// via `#[props(into)]`, so you can write `storage: LocalStorage::default()`
+/// directly. Two handles compare equal only if they point at the same
+/// storage instance.
+#[derive(Clone, Default)]
+pub struct ThemeStore(Option<Rc<dyn ThemeStorage>>);
+
+impl ThemeStore {
+    /// Construct an empty `ThemeStore` that never persists anything.
+    pub const fn none() -> Self {
+        Self(None)
+    }
+
+    /// Returns `true` if no storage is set.
+    pub fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+
+    /// Returns the saved theme, or `None` if there is no storage or nothing
+    /// has been saved yet.
+    pub fn load(&self) -> Option<BulmaTheme> {
+        self.0.as_ref()?.load()
+    }
+
+    /// Saves `theme`, if storage is set.
+    pub fn save(&self, theme: BulmaTheme) {
+        if let Some(storage) = &self.0 {
+            storage.save(theme);
+        }
+    }
+}
+
+impl PartialEq for ThemeStore {
+    fn eq(&self, other: &Self) -> bool {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<T: ThemeStorage + 'static> From<T> for ThemeStore {
+    fn from(storage: T) -> Self {
+        Self(Some(Rc::new(storage)))
+    }
+}
+
+/// [`ThemeStorage`] backed by the browser's `window.localStorage`.
+///
+/// Reads and writes fail silently (for example in private browsing modes
+/// where storage is disabled), in which case the provider falls back to its
+/// `theme` prop.
+#[cfg(feature = "web")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalStorage {
+    key: String,
+}
+
+#[cfg(feature = "web")]
+impl LocalStorage {
+    /// Stores the theme under `key`.
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+
+    fn storage() -> Option<web_sys::Storage> {
+        web_sys::window()?.local_storage().ok()?
+    }
+}
+
+#[cfg(feature = "web")]
+impl Default for LocalStorage {
+    /// Stores the theme under the `bulma-theme` key.
+    fn default() -> Self {
+        Self::new("bulma-theme")
+    }
+}
+
+#[cfg(feature = "web")]
+impl ThemeStorage for LocalStorage {
+    fn load(&self) -> Option<BulmaTheme> {
+        let value = Self::storage()?.get_item(&self.key).ok()??;
+        BulmaTheme::from_name(&value).ok()
+    }
+
+    fn save(&self, theme: BulmaTheme) {
+        if let Some(storage) = Self::storage() {
+            let _ = storage.set_item(&self.key, theme.variant_name());
+        }
+    }
+}
+
+/// Handle to the theme managed by the nearest [`BulmaProvider`] or
+/// [`ThemeScope`].
+///
+/// Obtained with [`use_bulma_theme`]. The handle is `Copy`, so it can be moved
+/// into event handlers freely. Changing the theme through it only re-renders
+/// the components that read it, and persists the choice to the provider's
+/// [`ThemeStorage`], if one was given.
+#[derive(Clone, Copy, PartialEq)]
+pub struct BulmaThemeContext {
+    theme: Signal<BulmaTheme>,
+    /// `None` until the renderer reports the OS color scheme.
+    prefers_dark: Signal<Option<bool>>,
+    effective: Memo<BulmaTheme>,
+    storage: CopyValue<ThemeStore>,
+}
+
+impl BulmaThemeContext {
+    /// Returns the currently selected theme, subscribing the caller to changes.
+    pub fn theme(&self) -> BulmaTheme {
+        (self.theme)()
+    }
+
+    /// Selects `theme` and persists it.
+    pub fn set_theme(&mut self, theme: BulmaTheme) {
+        self.theme.set(theme);
+        self.storage.peek().save(theme);
+    }
+
+    /// Returns the theme actually in effect: the selected theme, with `Auto`
+    /// resolved through the OS `prefers-color-scheme` setting (`Light` until
+    /// the setting is known). Never `Auto`.
+    pub fn effective_theme(&self) -> BulmaTheme {
+        (self.effective)()
+    }
+
+    /// Returns `true` if the dark scheme is in effect.
+    pub fn is_dark(&self) -> bool {
+        self.effective_theme() == BulmaTheme::Dark
+    }
+
+    /// Switches to `Light` when the effective theme is `Dark`, and to `Dark`
+    /// otherwise.
+    pub fn toggle(&mut self) {
+        let next = match *self.effective.peek() {
+            BulmaTheme::Dark => BulmaTheme::Light,
+            _ => BulmaTheme::Dark,
+        };
+        self.set_theme(next);
+    }
+
+    /// Returns the underlying signal, for use with `use_memo` and friends.
+    /// Writing to it directly bypasses persistence.
+    pub fn signal(&self) -> Signal<BulmaTheme> {
+        self.theme
+    }
+
+    /// Returns the memo behind [`BulmaThemeContext::effective_theme`], which
+    /// updates live when the OS color scheme changes.
+    pub fn effective_signal(&self) -> Memo<BulmaTheme> {
+        self.effective
+    }
+
+    /// The `data-theme` value to render. `Auto` gets none until the OS
+    /// scheme is known, so Bulma's own `prefers-color-scheme` rules apply
+    /// during server-side rendering and before JavaScript runs.
+    fn data_theme(&self) -> Option<&'static str> {
+        match (self.theme)() {
+            BulmaTheme::Auto => (self.prefers_dark)().map(|dark| BulmaTheme::Auto.resolve(dark).variant_name()),
+            theme => Some(theme.variant_name()),
+        }
+    }
+}
+
+/// Reports the OS color scheme now and on every change.
+const PREFERS_DARK_JS: &str = r#"
+    const query = window.matchMedia("(prefers-color-scheme: dark)");
+    dioxus.send(query.matches);
+    query.addEventListener("change", (event) => dioxus.send(event.matches));
+    await new Promise(() => {});
+"#;
+
+/// Tracks the OS `prefers-color-scheme` setting. Stays `None` on renderers
+/// without JavaScript, such as server-side rendering.
+fn use_prefers_dark() -> Signal<Option<bool>> {
+    let mut prefers_dark = use_signal(|| None);
+    use_future(move || async move {
+        let mut query = document::eval(PREFERS_DARK_JS);
+        while let Ok(dark) = query.recv::<bool>().await {
+            prefers_dark.set(Some(dark));
+        }
+    });
+    prefers_dark
+}
+
+/// Reads and updates the theme of the nearest [`BulmaProvider`] or
+/// [`ThemeScope`].
+///
+/// # Panics
+///
+/// Panics if called outside of a `BulmaProvider` or `ThemeScope`.
+///
+/// ```rust,ignore
+/// let mut theme = use_bulma_theme();
+///
+/// rsx! {
+///     Button {
+///         onclick: move |_| theme.toggle(),
+///         if theme.theme() == BulmaTheme::Dark { "Light mode" } else { "Dark mode" }
+///     }
+/// }
+/// ```
+pub fn use_bulma_theme() -> BulmaThemeContext {
+    use_context()
+}
+
 #[derive(Props, Clone, PartialEq)]
 pub struct BulmaProviderProps {
+    /// Theme used on startup when `storage` holds no saved choice.
     #[props(default)]
     pub theme: Option<BulmaTheme>,
+    /// Where to persist the theme selected through [`use_bulma_theme`].
+    #[props(default, into)]
+    pub storage: ThemeStore,
     #[props(default = true)]
     pub load_bulma_css: bool,
+    /// Where to load Bulma from when `load_bulma_css` is set.
+    #[props(default)]
+    pub css_source: BulmaCssSource,
+    /// Overrides Bulma's `--bulma-*` custom properties for the whole page.
+    #[props(default)]
+    pub config: Option<BulmaThemeConfig>,
+    /// Subresource Integrity hash (e.g. `"sha384-..."`) checked against a
+    /// linked stylesheet. Ignored for [`BulmaCssSource::Inline`].
+    #[props(default)]
+    pub css_integrity: Option<String>,
+    /// Fallback props for components inside the provider; see
+    /// [`use_bulma_defaults`](crate::defaults::use_bulma_defaults).
+    #[props(default)]
+    pub defaults: Option<BulmaDefaults>,
     pub children: Element,
 }
 
 #[component]
 pub fn BulmaProvider(props: BulmaProviderProps) -> Element {
-    let theme_mode = props.theme.unwrap_or_default();
-    
-    let theme_class = match theme_mode {
-        BulmaTheme::Auto => "theme-auto",
-        BulmaTheme::Light => "theme-light", 
-        BulmaTheme::Dark => "theme-dark",
-    };
-    
+    let initial_theme = props.theme;
+    let storage = props.storage.clone();
+
+    let theme = use_signal(|| storage.load().or(initial_theme).unwrap_or_default());
+    let mut storage = use_hook(|| CopyValue::new(storage));
+    if *storage.peek() != props.storage {
+        storage.set(props.storage.clone());
+    }
+    let prefers_dark = use_prefers_dark();
+    let effective = use_memo(move || theme().resolve(prefers_dark().unwrap_or(false)));
+    let context = use_context_provider(|| BulmaThemeContext { theme, prefers_dark, effective, storage });
+
+    let component_defaults = props.defaults.unwrap_or_default();
+    let mut defaults = use_context_provider(|| Signal::new(component_defaults));
+    use_effect(use_reactive!(|component_defaults| {
+        if *defaults.peek() != component_defaults {
+            defaults.set(component_defaults);
+        }
+    }));
+
+    // Mirror the scheme on <html> so the page background outside the
+    // provider follows it too.
+    use_effect(move || {
+        document::eval(&match context.data_theme() {
+            Some(scheme) => format!("document.documentElement.setAttribute('data-theme', '{scheme}');"),
+            None => "document.documentElement.removeAttribute('data-theme');".to_string(),
+        });
+    });
+
+    let theme_class = context.theme().as_class();
+    let data_theme = context.data_theme();
+    let config_css = props.config.as_ref().map(|config| config.to_stylesheet(":root"));
+
     rsx! {
         if props.load_bulma_css {
-            document::Link {
-                rel: "stylesheet",
-                href: "https://cdn.jsdelivr.net/npm/bulma@1.0.0/css/bulma.min.css"
+            if let BulmaCssSource::Inline(css) = &props.css_source {
+                document::Style { "{css}" }
+            } else {
+                document::Link {
+                    rel: "stylesheet",
+                    href: props.css_source.href(),
+                    integrity: props.css_integrity.clone(),
+                    crossorigin: props.css_integrity.as_ref().map(|_| "anonymous".to_string()),
+                }
             }
         }
-        div { 
+        if let Some(css) = config_css {
+            document::Style { "{css}" }
+        }
+        div {
             class: "{theme_class}",
+            "data-theme": data_theme,
             style: "min-height: 100vh;",
             {props.children}
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Props, Clone, PartialEq)]
+pub struct ThemeScopeProps {
+    /// Theme of this region. When unset on the first render, the region
+    /// shares the enclosing theme for good and only applies `config`.
+    #[props(default)]
+    pub theme: Option<BulmaTheme>,
+    /// Overrides Bulma's `--bulma-*` custom properties inside this region.
+    #[props(default)]
+    pub config: Option<BulmaThemeConfig>,
+    #[props(default)]
+    pub id: Option<String>,
+    #[props(default)]
+    pub class: Option<String>,
+    #[props(default)]
+    pub style: Option<String>,
+    pub children: Element,
+}
+
+/// Applies a [`BulmaTheme`] and/or [`BulmaThemeConfig`] to its subtree only,
+/// e.g. for a dark navbar or sidebar on an otherwise light page.
+///
+/// Descendants calling [`use_bulma_theme`] get this region's theme; changing
+/// it there only affects the region and is never persisted. The region paints
+/// its own scheme background and text color, which `class` or `style` can
+/// override.
+///
+/// ```rust,ignore
+/// BulmaProvider {
+///     theme: BulmaTheme::Light,
+///     ThemeScope {
+///         theme: BulmaTheme::Dark,
+///         Navbar { /* ... */ }
+///     }
+///     Section { /* light content */ }
+/// }
+/// ```
+#[component]
+pub fn ThemeScope(props: ThemeScopeProps) -> Element {
+    let parent = try_use_context::<BulmaThemeContext>();
+    let scope_theme = props.theme;
+
+    let owns_theme = use_hook(|| scope_theme.is_some() || parent.is_none());
+
+    let context = use_context_provider(|| match (owns_theme, parent) {
+        (false, Some(parent)) => parent,
+        _ => {
+            // Outside of a provider there is nothing tracking the OS scheme,
+            // so `Auto` resolves to light and leaves `data-theme` unset.
+            let prefers_dark = parent.map(|parent| parent.prefers_dark).unwrap_or_else(|| Signal::new(None));
+            let theme = Signal::new(scope_theme.unwrap_or_default());
+            let effective = Memo::new(move || theme().resolve(prefers_dark().unwrap_or(false)));
+            BulmaThemeContext { theme, prefers_dark, effective, storage: CopyValue::new(ThemeStore::none()) }
+        }
+    });
+
+    // Follow changes of the `theme` prop made by the component rendering us.
+    // A region sharing its parent's theme never writes to it.
+    let mut theme = context.theme;
+    use_effect(use_reactive!(|scope_theme| {
+        if owns_theme
+            && let Some(scope_theme) = scope_theme
+            && *theme.peek() != scope_theme
+        {
+            theme.set(scope_theme);
+        }
+    }));
+
+    let theme_class = context.theme().as_class();
+    let data_theme = context.data_theme();
+    let final_class = crate::classes![theme_class, &props.class];
+
+    let config_vars = props.config.as_ref().map(BulmaThemeConfig::to_css_vars).unwrap_or_default();
+    let user_style = props.style.as_deref().unwrap_or("");
+    let scope_style = format!(
+        "background-color: var(--bulma-scheme-main); color: var(--bulma-text); {config_vars}{user_style}"
+    );
+    let color_rules = props.config.as_ref().map(|config| {
+        config.custom_colors.iter().map(CustomColor::to_css_rules).collect::<Vec<_>>().join("\n")
+    });
+
+    rsx! {
+        if let Some(css) = color_rules.filter(|css| !css.is_empty()) {
+            document::Style { "{css}" }
+        }
+        div {
+            class: "{final_class}",
+            "data-theme": data_theme,
+            style: "{scope_style}",
+            id: props.id.clone(),
+            {props.children}
+        }
+    }
+}
@@ -1,19 +1,202 @@
-/// Utility function to build CSS class strings from optional classes
-pub fn build_class(base_classes: &[&str], optional_classes: &[Option<String>]) -> String {
-    let mut classes = base_classes.iter()
-        .filter(|&&class| !class.is_empty())
-        .map(|&class| class.to_string())
-        .collect::<Vec<String>>();
-    
-    for optional in optional_classes {
-        if let Some(class) = optional {
-            if !class.is_empty() {
-                classes.push(class.clone());
+use std::borrow::Cow;
+use std::fmt;
+
+/// A `class` attribute built up in a single buffer.
+///
+/// Classes are appended in order, separated by single spaces. Empty strings
+/// are skipped and classes already present are not added twice, so `&'static
+/// str` modifiers, optional user classes and multi-class strings can be mixed
+/// freely without intermediate allocations. Components build one with the
+/// [`classes!`](crate::classes) macro:
+///
+/// ```rust
+/// use dioxus_bulma::classes;
+///
+/// let rounded = true;
+/// let user_class: Option<String> = Some("is-rounded my-button".into());
+/// let class = classes!["button", ("is-", "primary"), rounded.then_some("is-rounded"), &user_class];
+/// assert_eq!(class, "button is-primary is-rounded my-button");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ClassList {
+    buf: String,
+}
+
+impl ClassList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Reserves room for `capacity` bytes of class names up front.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { buf: String::with_capacity(capacity) }
+    }
+
+    /// Appends every whitespace-separated class in `class`.
+    pub fn add(&mut self, class: impl ClassPart) -> &mut Self {
+        class.add_to(self);
+        self
+    }
+
+    /// Appends `class` when `condition` holds.
+    pub fn add_if(&mut self, condition: bool, class: &str) -> &mut Self {
+        if condition {
+            self.push_str(class);
+        }
+        self
+    }
+
+    /// Appends `prefix` followed by `name` (`"is-"` + `"primary"`) without
+    /// formatting an intermediate string.
+    pub fn add_prefixed(&mut self, prefix: &str, name: &str) -> &mut Self {
+        self.add_joined(&[prefix, name])
+    }
+
+    /// Appends the concatenation of `parts` as a single class, e.g.
+    /// `["is-", "half", "-tablet"]`. Nothing is added if the last part is
+    /// empty, so values without a class can be passed through.
+    pub fn add_joined(&mut self, parts: &[&str]) -> &mut Self {
+        if parts.last().is_none_or(|last| last.is_empty()) || self.contains_joined(parts) {
+            return self;
+        }
+        if !self.buf.is_empty() {
+            self.buf.push(' ');
+        }
+        for part in parts {
+            self.buf.push_str(part);
+        }
+        self
+    }
+
+    /// Appends every whitespace-separated class in `classes`.
+    pub fn push_str(&mut self, classes: &str) {
+        for class in classes.split_whitespace() {
+            self.add_joined(&[class]);
+        }
+    }
+
+    /// Returns `true` if `class` has already been added.
+    pub fn contains(&self, class: &str) -> bool {
+        self.contains_joined(&[class])
+    }
+
+    fn contains_joined(&self, parts: &[&str]) -> bool {
+        let len: usize = parts.iter().map(|part| part.len()).sum();
+        self.buf.split(' ').any(|existing| {
+            existing.len() == len && {
+                let mut rest = existing;
+                parts.iter().all(|part| match rest.strip_prefix(part) {
+                    Some(tail) => {
+                        rest = tail;
+                        true
+                    }
+                    None => false,
+                })
             }
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.buf
+    }
+
+    pub fn into_string(self) -> String {
+        self.buf
+    }
+}
+
+impl fmt::Display for ClassList {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.buf)
+    }
+}
+
+impl From<ClassList> for String {
+    fn from(list: ClassList) -> Self {
+        list.buf
+    }
+}
+
+/// Something that can be appended to a [`ClassList`].
+///
+/// Implemented for string types, `Option`s of them (`None` adds nothing) and
+/// `(prefix, name)` pairs, which are joined without allocating.
+pub trait ClassPart {
+    fn add_to(self, list: &mut ClassList);
+}
+
+impl ClassPart for &str {
+    fn add_to(self, list: &mut ClassList) {
+        list.push_str(self);
+    }
+}
+
+impl ClassPart for String {
+    fn add_to(self, list: &mut ClassList) {
+        list.push_str(&self);
+    }
+}
+
+impl ClassPart for &String {
+    fn add_to(self, list: &mut ClassList) {
+        list.push_str(self);
+    }
+}
+
+impl ClassPart for Cow<'_, str> {
+    fn add_to(self, list: &mut ClassList) {
+        list.push_str(&self);
+    }
+}
+
+impl ClassPart for (&str, &str) {
+    fn add_to(self, list: &mut ClassList) {
+        list.add_prefixed(self.0, self.1);
+    }
+}
+
+impl<T: ClassPart> ClassPart for Option<T> {
+    fn add_to(self, list: &mut ClassList) {
+        if let Some(part) = self {
+            part.add_to(list);
         }
     }
-    
-    classes.join(" ")
+}
+
+impl ClassPart for &Option<String> {
+    fn add_to(self, list: &mut ClassList) {
+        self.as_deref().add_to(list);
+    }
+}
+
+/// Builds a `class` string from any number of [`ClassPart`]s.
+///
+/// Expands to a single [`ClassList`] filled in order; the only allocation is
+/// the returned `String`.
+#[macro_export]
+macro_rules! classes {
+    ($($part:expr),* $(,)?) => {{
+        let mut list = $crate::utils::ClassList::with_capacity(64);
+        $( list.add($part); )*
+        list.into_string()
+    }};
+}
+
+/// Utility function to build CSS class strings from optional classes
+#[deprecated(note = "use the `classes!` macro or `ClassList` instead")]
+pub fn build_class(base_classes: &[&str], optional_classes: &[Option<String>]) -> String {
+    let mut list = ClassList::new();
+    for class in base_classes {
+        list.add(*class);
+    }
+    for class in optional_classes {
+        list.add(class);
+    }
+    list.into_string()
 }
 
 /// Utility function to conditionally add classes
@@ -23,4 +206,4 @@ pub fn conditional_class(condition: bool, class: &str) -> Option<String> {
     } else {
         None
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,84 @@
+//! Tests for deriving the active state of router links from the current route.
+#![cfg(feature = "router")]
+
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+fn target(path: &str) -> NavigationTarget {
+    NavigationTarget::Internal(path.to_string())
+}
+
+#[test]
+fn exact_match_ignores_query_and_trailing_slash() {
+    let docs = target("/docs");
+    assert!(ActiveMatch::Exact.is_active(Some(&docs), "/docs"));
+    assert!(ActiveMatch::Exact.is_active(Some(&docs), "/docs/?tab=api#intro"));
+    assert!(!ActiveMatch::Exact.is_active(Some(&docs), "/docs/intro"));
+    assert!(ActiveMatch::Exact.is_active(Some(&target("/")), "/"));
+    assert!(!ActiveMatch::Exact.is_active(None, "/docs"));
+}
+
+#[test]
+fn prefix_match_respects_segments() {
+    let docs = target("/docs");
+    assert!(ActiveMatch::Prefix.is_active(Some(&docs), "/docs"));
+    assert!(ActiveMatch::Prefix.is_active(Some(&docs), "/docs/intro"));
+    assert!(!ActiveMatch::Prefix.is_active(Some(&docs), "/docs-old"));
+    assert!(ActiveMatch::Prefix.is_active(Some(&target("/")), "/anything"));
+}
+
+#[test]
+fn custom_match_sees_current_path() {
+    let settings = ActiveMatch::custom(|path| path == "/profile" || path.starts_with("/settings"));
+    assert!(settings.is_active(None, "/profile?edit=1"));
+    assert!(settings.is_active(Some(&target("/settings")), "/settings/security"));
+    assert!(!settings.is_active(None, "/docs"));
+    assert_eq!(settings.clone(), settings);
+    assert_ne!(settings, ActiveMatch::custom(|_| true));
+    assert!(!ActiveMatch::Exact.is_active(Some(&NavigationTarget::External("https://bulma.io".into())), "/"));
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/docs/intro")]
+    DocsIntro {},
+}
+
+#[component]
+fn DocsIntro() -> Element {
+    rsx! {
+        MenuList {
+            MenuItem { to: target("/docs"), active_match: ActiveMatch::Prefix, "Docs" }
+            MenuItem { to: target("/"), active_match: ActiveMatch::Exact, "Home" }
+            MenuItem { to: target("/docs"), active_match: ActiveMatch::Prefix, active: false, "Forced off" }
+        }
+        Tabs { Tab { to: target("/blog"), active_match: ActiveMatch::Prefix, "Blog" } }
+        NavbarItem { to: target("/docs/intro"), active_match: ActiveMatch::Exact, "Intro" }
+        Breadcrumb { BreadcrumbItem { to: target("/docs/intro"), active_match: ActiveMatch::Exact, "Intro" } }
+        Panel { PanelBlock { to: target("/docs"), active_match: ActiveMatch::Exact, "Docs" } }
+    }
+}
+
+#[test]
+fn active_match_follows_current_route() {
+    fn app() -> Element {
+        use_hook(|| {
+            let history: std::rc::Rc<dyn dioxus::history::History> =
+                std::rc::Rc::new(dioxus::history::MemoryHistory::with_initial_path("/docs/intro"));
+            provide_context(history)
+        });
+        rsx! { Router::<Route> {} }
+    }
+
+    let html = common::render(app);
+    assert!(html.contains(r#"<li><a href="/docs" class="is-active" aria-current="page">Docs</a></li><li><a href="/">Home</a></li>"#), "{html}");
+    // An explicit `active` wins over `active_match`.
+    assert!(html.contains(r#"<li><a href="/docs">Forced off</a></li>"#), "{html}");
+    assert!(html.contains(r#"<li class="" style=""><a href="/blog">Blog</a></li>"#), "{html}");
+    assert!(html.contains(r#"<a href="/docs/intro" class="navbar-item is-active" aria-current="page">Intro</a>"#), "{html}");
+    assert!(html.contains(r#"<li class="is-active" style=""><a href="/docs/intro" aria-current="page">Intro</a></li>"#), "{html}");
+    assert!(html.contains(r#"<a href="/docs" class="panel-block">Docs</a>"#), "{html}");
+}
@@ -1,6 +1,8 @@
 use dioxus_bulma::prelude::*;
 use dioxus::prelude::*;
 
+mod common;
+
 #[test]
 fn test_enum_visibility() {
     // Test that all enums are publicly accessible
@@ -48,5 +50,87 @@ fn test_component_construction() {
     };
 
     // If this compiles, the API is working
-    assert!(true);
-}
\ No newline at end of file
+}
+#[test]
+fn test_color_shades() {
+    let primary = BulmaColor::Primary;
+
+    assert_eq!(ColorShade::Light.fill_class(primary), "is-light");
+    assert_eq!(ColorShade::Dark.fill_class(primary), "is-dark");
+    assert_eq!(
+        ColorShade::Step(90).fill_class(primary),
+        "has-background-primary-90 has-text-primary-90-invert"
+    );
+    assert_eq!(ColorShade::Dark.text_class(primary), "has-text-primary-dark");
+    assert_eq!(ColorShade::Step(5).text_class(primary), "has-text-primary-05");
+
+    // Steps snap down to Bulma's multiples of 5 and are capped at 100.
+    assert_eq!(ColorShade::Step(0).step_suffix().as_deref(), Some("00"));
+    assert_eq!(ColorShade::Step(47).step_suffix().as_deref(), Some("45"));
+    assert_eq!(ColorShade::Step(250).step_suffix().as_deref(), Some("100"));
+    assert_eq!(ColorShade::Light.step_suffix(), None);
+
+    // Components without Bulma light/dark variants use helper classes, since
+    // `is-light`/`is-dark` are colors of their own there.
+    assert_eq!(
+        ColorShade::Light.background_class(primary),
+        "has-background-primary-light has-text-primary-dark"
+    );
+    assert_eq!(
+        ColorShade::Dark.background_class(primary),
+        "has-background-primary-dark has-text-primary-light"
+    );
+    assert_eq!(
+        ColorShade::Step(90).background_class(primary),
+        ColorShade::Step(90).fill_class(primary)
+    );
+
+    let html = common::render(|| rsx! {
+        Button { color: BulmaColor::Primary, shade: ColorShade::Light, "Soft" }
+        Tag { color: BulmaColor::Info, shade: ColorShade::Step(90), "Pale" }
+        Notification { color: BulmaColor::Danger, shade: ColorShade::Dark, "Careful" }
+        Hero { color: BulmaColor::Success, shade: ColorShade::Light, "x" }
+        Message { color: BulmaColor::Info, shade: ColorShade::Dark, "m" }
+        Icon { color: BulmaColor::Warning, shade: ColorShade::Dark, "!" }
+    });
+    assert!(html.contains(r#"class="button is-primary is-light""#), "{html}");
+    assert!(html.contains("tag is-info has-background-info-90 has-text-info-90-invert"), "{html}");
+    assert!(html.contains("notification is-danger is-dark"), "{html}");
+    assert!(html.contains("hero is-success has-background-success-light has-text-success-dark"), "{html}");
+    assert!(!html.contains("hero is-success is-light"), "{html}");
+    assert!(html.contains("message is-info has-background-info-dark has-text-info-light"), "{html}");
+    assert!(html.contains("has-text-warning has-text-warning-dark"), "{html}");
+}
+
+#[test]
+fn test_shaded_controls_color_inline() {
+    assert_eq!(
+        ColorShade::Step(90).color_value(BulmaColor::Primary),
+        "hsl(var(--bulma-primary-h), var(--bulma-primary-s), var(--bulma-primary-90-l))"
+    );
+    assert_eq!(
+        ColorShade::Dark.color_value(BulmaColor::Info),
+        "hsl(var(--bulma-info-h), var(--bulma-info-s), var(--bulma-info-dark-l))"
+    );
+
+    let html = common::render(|| rsx! {
+        Input { color: BulmaColor::Primary, shade: ColorShade::Step(90), style: "width: 10rem;" }
+        Textarea { color: BulmaColor::Danger, shade: ColorShade::Dark }
+        Select { color: BulmaColor::Info, shade: ColorShade::Light, option { "One" } }
+        Progress { color: BulmaColor::Success, shade: ColorShade::Step(40), value: 20.0 }
+        Input { shade: ColorShade::Dark }
+    });
+
+    assert!(html.contains(
+        r#"class="input is-primary" style="border-color: hsl(var(--bulma-primary-h), var(--bulma-primary-s), var(--bulma-primary-90-l)); width: 10rem;""#
+    ), "{html}");
+    assert!(html.contains("border-color: hsl(var(--bulma-danger-h), var(--bulma-danger-s), var(--bulma-danger-dark-l));"), "{html}");
+    assert!(html.contains(
+        r#"<select style="border-color: hsl(var(--bulma-info-h), var(--bulma-info-s), var(--bulma-info-light-l));""#
+    ), "{html}");
+    assert!(html.contains(
+        "--bulma-progress-value-background-color: hsl(var(--bulma-success-h), var(--bulma-success-s), var(--bulma-success-40-l));"
+    ), "{html}");
+    // Without a color there is nothing to shade.
+    assert!(html.contains(r#"class="input" style="""#), "{html}");
+}
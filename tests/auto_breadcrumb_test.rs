@@ -0,0 +1,115 @@
+//! Tests for breadcrumbs derived from the current route.
+#![cfg(feature = "router")]
+
+use dioxus::prelude::*;
+use dioxus_bulma::components::{breadcrumb_trail, BreadcrumbSeparator};
+use dioxus_bulma::prelude::*;
+
+mod common;
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    #[route("/")]
+    Home {},
+    #[route("/device-list")]
+    DeviceList {},
+    #[route("/device-list/:id")]
+    Device { id: u32 },
+    #[route("/:..segments")]
+    NotFound { segments: Vec<String> },
+}
+
+impl BreadcrumbLabel for Route {
+    fn breadcrumb_label(&self) -> Option<String> {
+        match self {
+            Route::Device { id } => Some(format!("Device #{id}")),
+            _ => None,
+        }
+    }
+
+    fn in_breadcrumb(&self) -> bool {
+        !matches!(self, Route::NotFound { .. })
+    }
+}
+
+#[component]
+fn Home() -> Element {
+    rsx! { AutoBreadcrumb::<Route> {} }
+}
+
+#[component]
+fn DeviceList() -> Element {
+    rsx! { AutoBreadcrumb::<Route> {} }
+}
+
+#[component]
+fn Device(id: u32) -> Element {
+    rsx! { AutoBreadcrumb::<Route> { home_label: "Start" } }
+}
+
+#[component]
+fn NotFound(segments: Vec<String>) -> Element {
+    rsx! {}
+}
+
+fn labels(path: &str) -> Vec<String> {
+    breadcrumb_trail::<Route>(path, "Home").into_iter().map(|(_, label)| label).collect()
+}
+
+#[test]
+fn trail_follows_path_prefixes() {
+    assert_eq!(labels("/"), ["Home"]);
+    assert_eq!(labels("/device-list"), ["Home", "Device list"]);
+    assert_eq!(labels("/device-list/42?tab=logs#top"), ["Home", "Device list", "Device #42"]);
+
+    let trail = breadcrumb_trail::<Route>("/device-list/7", "Start");
+    assert_eq!(trail[0], (Route::Home {}, "Start".to_string()));
+    assert_eq!(trail[2].0, Route::Device { id: 7 });
+}
+
+#[test]
+fn trail_skips_hidden_routes() {
+    assert_eq!(labels("/device-list/not-a-number"), ["Home", "Device list"]);
+    assert_eq!(labels("/missing/page"), ["Home"]);
+}
+
+fn render_at(path: &'static str) -> String {
+    thread_local! {
+        static PATH: std::cell::Cell<&'static str> = const { std::cell::Cell::new("/") };
+    }
+    fn app() -> Element {
+        use_hook(|| {
+            let history: std::rc::Rc<dyn dioxus::history::History> =
+                std::rc::Rc::new(dioxus::history::MemoryHistory::with_initial_path(PATH.with(|p| p.get())));
+            provide_context(history)
+        });
+        rsx! { Router::<Route> {} }
+    }
+
+    PATH.with(|p| p.set(path));
+    common::render(app)
+}
+
+#[test]
+fn auto_breadcrumb_renders_inside_and_outside_router() {
+    // `Device` passes a custom `home_label`.
+    let html = render_at("/device-list/42");
+    assert!(html.contains(concat!(
+        r#"<li class="" style=""><a href="/">Start</a></li>"#,
+        r#"<li class="" style=""><a href="/device-list">Device list</a></li>"#,
+        r#"<li class="is-active" style=""><a href="/device-list/42" aria-current="page">Device #42</a></li></ul>"#,
+    )), "{html}");
+    assert_eq!(html.matches("is-active").count(), 1, "{html}");
+    assert_eq!(html.matches("aria-current").count(), 1, "{html}");
+
+    let html = render_at("/");
+    assert!(html.contains(r#"<ul><li class="is-active" style=""><a href="/" aria-current="page">Home</a></li></ul>"#), "{html}");
+
+    fn unrouted() -> Element {
+        rsx! { AutoBreadcrumb::<Route> { separator: BreadcrumbSeparator::Arrow } }
+    }
+
+    let html = common::render(unrouted);
+    assert_eq!(html, r#"<nav class="breadcrumb has-arrow-separator" style="" aria-label="breadcrumbs"><ul></ul></nav>"#);
+}
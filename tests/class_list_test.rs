@@ -0,0 +1,32 @@
+//! Tests for `ClassList` and the `classes!` macro.
+use dioxus_bulma::classes;
+use dioxus_bulma::prelude::*;
+use dioxus_bulma::utils::ClassList;
+
+#[test]
+fn classes_skips_empty_and_duplicate_classes() {
+    let rounded = true;
+    let loading = false;
+    let user_class: Option<String> = Some("button  custom is-rounded".to_string());
+    let class = classes![
+        "button",
+        ("is-", BulmaColor::Primary.as_str()),
+        BulmaSize::Normal.as_class(),
+        rounded.then_some("is-rounded"),
+        loading.then_some("is-loading"),
+        &Helpers::new().margin(Side::Top, 2),
+        &user_class,
+    ];
+    assert_eq!(class, "button is-primary is-rounded mt-2 custom");
+    assert_eq!(classes![None::<&str>, ""], "");
+}
+
+#[test]
+fn class_list_matches_whole_classes_only() {
+    let mut list = ClassList::new();
+    list.add("is-primary").add_prefixed("is-", "primary-dark").add_if(true, "is-primary");
+    assert_eq!(list.as_str(), "is-primary is-primary-dark");
+    assert!(list.contains("is-primary-dark"));
+    assert!(!list.contains("is-prim"));
+    assert_eq!(list.to_string(), String::from(list.clone()));
+}
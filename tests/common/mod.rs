@@ -0,0 +1,96 @@
+//! Renders a built `VirtualDom` to an HTML string so tests can assert on the
+//! classes and attributes a component produces.
+
+#![allow(dead_code)]
+
+use dioxus::dioxus_core::{AttributeValue, DynamicNode, TemplateAttribute, TemplateNode, VNode};
+use dioxus::prelude::*;
+
+/// Builds `app` and returns its HTML.
+pub fn render(app: fn() -> Element) -> String {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    render_dom(&dom)
+}
+
+/// Returns the HTML of an already built `VirtualDom`.
+pub fn render_dom(dom: &VirtualDom) -> String {
+    let mut html = String::new();
+    write_vnode(&mut html, dom.base_scope().root_node(), dom);
+    html
+}
+
+fn write_vnode(html: &mut String, vnode: &VNode, dom: &VirtualDom) {
+    for root in vnode.template.roots {
+        write_template_node(html, root, vnode, dom);
+    }
+}
+
+fn write_template_node(html: &mut String, node: &TemplateNode, vnode: &VNode, dom: &VirtualDom) {
+    match node {
+        TemplateNode::Element { tag, attrs, children, .. } => {
+            let mut attributes: Vec<(&str, String)> = Vec::new();
+            let mut push = |name: &'static str, value: String| match attributes.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, existing)) if name == "class" => {
+                    existing.push(' ');
+                    existing.push_str(&value);
+                }
+                Some((_, existing)) if name == "style" => existing.push_str(&value),
+                _ => attributes.push((name, value)),
+            };
+            for attr in attrs.iter() {
+                match attr {
+                    TemplateAttribute::Static { name, value, namespace } => match namespace {
+                        Some("style") => push("style", format!("{name}:{value};")),
+                        _ => push(name, value.to_string()),
+                    },
+                    TemplateAttribute::Dynamic { id } => {
+                        for attr in vnode.dynamic_attrs[*id].iter() {
+                            let value = match &attr.value {
+                                AttributeValue::Text(text) => text.clone(),
+                                AttributeValue::Float(value) => value.to_string(),
+                                AttributeValue::Int(value) => value.to_string(),
+                                AttributeValue::Bool(true) => "true".to_string(),
+                                _ => continue,
+                            };
+                            match attr.namespace {
+                                Some("style") => push("style", format!("{}:{value};", attr.name)),
+                                _ => push(attr.name, value),
+                            }
+                        }
+                    }
+                }
+            }
+
+            html.push('<');
+            html.push_str(tag);
+            for (name, value) in attributes {
+                html.push_str(&format!(" {name}=\"{value}\""));
+            }
+            html.push('>');
+            for child in children.iter() {
+                write_template_node(html, child, vnode, dom);
+            }
+            html.push_str(&format!("</{tag}>"));
+        }
+        TemplateNode::Text { text } => html.push_str(text),
+        TemplateNode::Dynamic { id } => write_dynamic_node(html, *id, vnode, dom),
+    }
+}
+
+fn write_dynamic_node(html: &mut String, index: usize, vnode: &VNode, dom: &VirtualDom) {
+    match &vnode.dynamic_nodes[index] {
+        DynamicNode::Component(component) => {
+            if let Some(scope) = component.mounted_scope(index, vnode, dom) {
+                write_vnode(html, scope.root_node(), dom);
+            }
+        }
+        DynamicNode::Text(text) => html.push_str(&text.value),
+        DynamicNode::Placeholder(_) => {}
+        DynamicNode::Fragment(children) => {
+            for child in children {
+                write_vnode(html, child, dom);
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Tests for `DataTable` column definitions and sorting.
+mod common;
+
+use std::collections::BTreeSet;
+
+use dioxus::prelude::*;
+use dioxus_bulma::components::{key_range, sorted_row_order};
+use dioxus_bulma::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Device {
+    name: &'static str,
+    uptime_days: u32,
+}
+
+fn devices() -> Vec<Device> {
+    vec![
+        Device { name: "gateway", uptime_days: 12 },
+        Device { name: "camera", uptime_days: 40 },
+        Device { name: "sensor", uptime_days: 12 },
+    ]
+}
+
+fn columns() -> Vec<TableColumn<Device>> {
+    vec![
+        TableColumn::new("Name", |device: &Device| rsx! { "{device.name}" }).sort_by_key(|device| device.name),
+        TableColumn::new("Uptime", |device: &Device| rsx! { "{device.uptime_days} days" })
+            .sort_by_key(|device| device.uptime_days)
+            .align(TextAlignment::Right)
+            .width("8rem")
+            .footer(|devices| {
+                let total: u32 = devices.iter().map(|device| device.uptime_days).sum();
+                rsx! { "{total} days" }
+            }),
+        TableColumn::new("Notes", |_: &Device| rsx! { "—" }),
+    ]
+}
+
+#[test]
+fn rows_sort_stably_by_column() {
+    let rows = devices();
+    let columns = columns();
+
+    assert_eq!(sorted_row_order(&rows, &columns, None), [0, 1, 2]);
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::ascending(0))), [1, 0, 2]);
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::descending(0))), [2, 0, 1]);
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::ascending(1))), [0, 2, 1]);
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::descending(1))), [1, 0, 2]);
+    // Unsortable and unknown columns keep the rows' order.
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::ascending(2))), [0, 1, 2]);
+    assert_eq!(sorted_row_order(&rows, &columns, Some(TableSort::ascending(9))), [0, 1, 2]);
+}
+
+#[test]
+fn header_clicks_cycle_through_sorts() {
+    let ascending = TableSort::toggle(None, 1);
+    assert_eq!(ascending, Some(TableSort::ascending(1)));
+    let descending = TableSort::toggle(ascending, 1);
+    assert_eq!(descending, Some(TableSort::descending(1)));
+    assert_eq!(TableSort::toggle(descending, 1), None);
+    assert_eq!(TableSort::toggle(descending, 0), Some(TableSort::ascending(0)));
+    assert_eq!(SortDirection::Descending.aria_sort(), "descending");
+}
+
+#[test]
+fn columns_compare_by_identity() {
+    let columns = self::columns();
+    assert_eq!(columns[0].clone(), columns[0]);
+    assert_ne!(self::columns()[0], columns[0]);
+    assert_eq!(columns[1].header(), "Uptime");
+    assert!(columns[1].is_sortable());
+    assert!(!columns[2].is_sortable());
+}
+
+#[test]
+fn data_table_renders() {
+    fn app() -> Element {
+        rsx! {
+            DataTable {
+                rows: devices(),
+                columns: columns(),
+                initial_sort: TableSort::descending(1),
+                on_sort: |_sort: Option<TableSort>| {},
+                striped: true,
+                hoverable: true,
+            }
+            DataTable {
+                rows: Vec::<Device>::new(),
+                columns: columns(),
+                empty: rsx! { "No devices registered yet." },
+            }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.starts_with(r#"<table class="table is-striped is-hoverable" style="">"#), "{html}");
+    // Sortable headers are focusable buttons; the sorted one shows its direction.
+    assert!(html.contains(r#"<th aria-sort="none"><button class="button is-ghost px-0" type="button">Name "#), "{html}");
+    assert!(
+        html.contains(r#"<th class="has-text-right" style="width: 8rem" aria-sort="descending"><button class="button is-ghost px-0" type="button">Uptime <span class="has-text-grey-light" aria-hidden="true">↓</span></button></th>"#),
+        "{html}"
+    );
+    assert!(html.contains(r#"<th>Notes</th>"#), "{html}");
+    // Unaligned cells carry no empty `class` attribute.
+    assert!(!html.contains(r#"<th class="""#) && !html.contains(r#"<td class="""#), "{html}");
+    let camera = html.find(">camera<").unwrap();
+    let gateway = html.find(">gateway<").unwrap();
+    let sensor = html.find(">sensor<").unwrap();
+    assert!(camera < gateway && gateway < sensor, "{html}");
+    assert!(html.contains(r#"<th class="has-text-right">64 days</th>"#), "{html}");
+    assert!(html.contains(r#"<td class="has-text-centered has-text-grey" colspan="3">No devices registered yet.</td>"#), "{html}");
+}
+
+#[test]
+fn key_ranges_span_either_direction() {
+    let keys: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+    assert_eq!(key_range(&keys, "b", "d"), ["b", "c", "d"]);
+    assert_eq!(key_range(&keys, "d", "b"), ["b", "c", "d"]);
+    assert_eq!(key_range(&keys, "c", "c"), ["c"]);
+    assert_eq!(key_range(&keys, "gone", "c"), ["c"]);
+    assert!(key_range(&keys, "a", "gone").is_empty());
+}
+
+#[test]
+fn selectable_data_table_renders() {
+    fn app() -> Element {
+        let selected = use_signal(|| BTreeSet::from(["camera".to_string()]));
+        rsx! {
+            DataTable {
+                rows: devices(),
+                columns: columns(),
+                selection: SelectionMode::Multiple,
+                row_key: |device: &Device| device.name.to_string(),
+                selected,
+                bulk_actions: rsx! {
+                    Button { color: BulmaColor::Danger, "Remove" }
+                },
+            }
+            DataTable {
+                rows: devices(),
+                columns: columns(),
+                selection: SelectionMode::Single,
+            }
+            DataTable {
+                rows: Vec::<Device>::new(),
+                columns: columns(),
+                selection: SelectionMode::Multiple,
+            }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.starts_with(r#"<nav class="level mb-3" style=""><div class="level-left" style=""><div class="level-item">1 selected</div>"#), "{html}");
+    // Rows are keyed by name, so only the camera row is selected.
+    assert_eq!(html.matches(r#"<tr class="is-selected">"#).count(), 1, "{html}");
+    assert!(html.contains(r#"aria-label="Select row" checked="true"></input></td><td>camera</td>"#), "{html}");
+    assert_eq!(html.matches(r#"<input type="radio""#).count(), 3, "{html}");
+    assert!(html.contains(r#"aria-label="Select all rows" disabled="true">"#), "{html}");
+}
+
+#[test]
+fn row_keys_default_to_the_row_index() {
+    let device = &devices()[1];
+    assert_eq!(RowKey::default().key(1, device), "1");
+
+    let by_name = RowKey::new(|device: &Device| device.name.to_string());
+    assert_eq!(by_name.key(1, device), "camera");
+    assert_eq!(by_name.clone(), by_name);
+    assert_ne!(by_name, RowKey::default());
+}
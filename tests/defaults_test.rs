@@ -0,0 +1,64 @@
+//! Tests for app-wide component defaults.
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+#[test]
+fn control_size_prefers_prop_then_component_then_density() {
+    let defaults = BulmaDefaults {
+        density: Density::Compact,
+        button: ComponentDefaults { size: Some(BulmaSize::Large), ..Default::default() },
+        ..Default::default()
+    };
+    assert_eq!(defaults.control_size(&defaults.button, Some(BulmaSize::Medium)), BulmaSize::Medium);
+    assert_eq!(defaults.control_size(&defaults.button, None), BulmaSize::Large);
+    assert_eq!(defaults.control_size(&defaults.tag, None), BulmaSize::Small);
+    assert_eq!(BulmaDefaults::default().control_size(&ComponentDefaults::default(), None), BulmaSize::Normal);
+    assert_eq!(Density::Spacious.size(), BulmaSize::Medium);
+}
+
+thread_local! {
+    static SEEN_DEFAULTS: std::cell::RefCell<Vec<BulmaDefaults>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn RecordDefaults() -> Element {
+    let defaults = use_bulma_defaults();
+    SEEN_DEFAULTS.with(|seen| seen.borrow_mut().push(defaults));
+    rsx! {}
+}
+
+#[test]
+fn provider_supplies_defaults() {
+    fn app() -> Element {
+        rsx! {
+            RecordDefaults {}
+            BulmaProvider {
+                load_bulma_css: false,
+                defaults: BulmaDefaults {
+                    density: Density::Compact,
+                    button: ComponentDefaults {
+                        color: Some(BulmaColor::Link),
+                        outlined: Some(true),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                RecordDefaults {}
+                Button { "Compact, outlined link button" }
+                Button { color: BulmaColor::Danger, outlined: false, "Explicit props win" }
+                Table { tbody {} }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    SEEN_DEFAULTS.with(|seen| {
+        let seen = seen.borrow();
+        assert_eq!(seen[0], BulmaDefaults::default());
+        assert_eq!(seen[1].density, Density::Compact);
+        assert_eq!(seen[1].button.color, Some(BulmaColor::Link));
+        assert_eq!(seen[1].button.outlined, Some(true));
+    });
+}
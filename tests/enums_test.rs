@@ -0,0 +1,110 @@
+//! Tests for enum names, `FromStr`, `Display`, `ALL` and serde support.
+use dioxus_bulma::components::{ColumnSize, ImageSize, TabsStyle, TileSize};
+use dioxus_bulma::prelude::*;
+use dioxus_bulma::ParseEnumError;
+
+fn assert_round_trips<T: BulmaEnum + std::str::FromStr<Err = ParseEnumError> + std::fmt::Debug>() {
+    let mut names = Vec::new();
+    for variant in T::all() {
+        let name = variant.variant_name();
+        assert!(!names.contains(&name), "duplicate name `{name}`");
+        names.push(name);
+        assert_eq!(name.parse::<T>().as_ref(), Ok(variant));
+        // Default modifiers such as `BulmaSize::Normal` display as no class.
+        let shown = variant.to_string();
+        if !shown.is_empty() {
+            assert_eq!(shown.parse::<T>().as_ref(), Ok(variant));
+        }
+    }
+}
+
+#[test]
+fn every_variant_round_trips_through_its_name() {
+    assert_round_trips::<BulmaTheme>();
+    assert_round_trips::<BulmaColor>();
+    assert_round_trips::<BulmaSize>();
+    assert_round_trips::<ColorShade>();
+    assert_round_trips::<Density>();
+    assert_round_trips::<Breakpoint>();
+    assert_round_trips::<TextAlignment>();
+    assert_round_trips::<Side>();
+    assert_round_trips::<FontWeight>();
+    assert_round_trips::<TextTransform>();
+    assert_round_trips::<FontFamily>();
+    assert_round_trips::<DisplayMode>();
+    assert_round_trips::<ColumnSize>();
+    assert_round_trips::<ImageSize>();
+    assert_round_trips::<TabsStyle>();
+    assert_round_trips::<TileSize>();
+    assert_round_trips::<TitleSize>();
+    assert_round_trips::<InputType>();
+    assert_round_trips::<ButtonsAlignment>();
+    assert_round_trips::<LinkFallback>();
+    assert_round_trips::<SortDirection>();
+    assert_round_trips::<SelectionMode>();
+}
+
+#[test]
+fn names_follow_bulma() {
+    assert_eq!(ColumnSize::ThreeQuarters.variant_name(), "three-quarters");
+    assert_eq!(ColumnSize::Twelve.variant_name(), "12");
+    assert_eq!(ImageSize::Is16by9.variant_name(), "16by9");
+    assert_eq!(TabsStyle::ToggleRounded.variant_name(), "toggle-rounded");
+    assert_eq!(FontFamily::SansSerif.variant_name(), "sans-serif");
+    assert_eq!(ColorShade::Step(7).variant_name(), "05");
+    assert_eq!(BulmaColor::Custom("brand").variant_name(), "brand");
+
+    assert_eq!("is-one-third".parse(), Ok(ColumnSize::OneThird));
+    assert_eq!(Breakpoint::FullHD.to_string(), "fullhd");
+    assert_eq!(ColumnSize::ALL.len(), 23);
+    assert_eq!(ColorShade::ALL[2..5], [ColorShade::Step(0), ColorShade::Step(5), ColorShade::Step(10)]);
+}
+
+#[test]
+fn unknown_names_are_rejected() {
+    let err = "purple".parse::<BulmaColor>().unwrap_err();
+    assert_eq!(err.type_name(), "BulmaColor");
+    assert_eq!(err.value(), "purple");
+    assert_eq!(err.to_string(), "unknown BulmaColor `purple`");
+    assert!("".parse::<TabsStyle>().is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_uses_kebab_case_names() {
+    #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq)]
+    struct ButtonConfig {
+        color: BulmaColor,
+        size: BulmaSize,
+        width: ColumnSize,
+        shade: Option<ColorShade>,
+    }
+
+    let config = ButtonConfig {
+        color: BulmaColor::Primary,
+        size: BulmaSize::Small,
+        width: ColumnSize::OneThird,
+        shade: Some(ColorShade::Step(90)),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"color":"primary","size":"small","width":"one-third","shade":"90"}"#);
+    assert_eq!(serde_json::from_str::<ButtonConfig>(&json).unwrap(), config);
+    assert!(serde_json::from_str::<BulmaSize>(r#""huge""#).is_err());
+}
+
+#[test]
+fn custom_colors_do_not_parse() {
+    // Only registered at runtime, so there is no name to parse back.
+    assert_eq!(BulmaColor::Custom("brand").to_string(), "is-brand");
+    assert!("brand".parse::<BulmaColor>().is_err());
+    assert!(!BulmaColor::ALL.contains(&BulmaColor::Custom("brand")));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_rejects_custom_colors() {
+    let err = serde_json::to_string(&BulmaColor::Custom("brand")).unwrap_err();
+    assert_eq!(err.to_string(), "unknown BulmaColor `brand` can't be serialized");
+    assert!(serde_json::from_str::<BulmaColor>(r#""brand""#).is_err());
+    assert_eq!(serde_json::to_string(&ColorShade::Step(90)).unwrap(), r#""90""#);
+}
@@ -0,0 +1,90 @@
+//! Tests for the footer components.
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+#[test]
+fn footer_link_builders() {
+    let group = FooterLinkGroup::new("Product")
+        .link(FooterLink::new("Pricing").href("/pricing"))
+        .link(FooterLink::new("Docs"));
+    assert_eq!(group.title, "Product");
+    assert_eq!(group.links.len(), 2);
+    assert_eq!(group.links[0].href.as_deref(), Some("/pricing"));
+    assert_eq!(group.links[1].href, None);
+}
+
+#[test]
+fn footers_render() {
+    fn app() -> Element {
+        rsx! {
+            Footer { Content { "Plain footer" } }
+            Footer { color: BulmaColor::Dark, size: BulmaSize::Small, "Dark footer" }
+            Footer { color: BulmaColor::Primary, shade: ColorShade::Light, "Light footer" }
+            Footer { color: BulmaColor::Info, shade: ColorShade::Step(90), "Pale footer" }
+            FooterColumns {
+                groups: vec![
+                    FooterLinkGroup::new("Product")
+                        .link(FooterLink::new("Pricing").href("/pricing")),
+                    FooterLinkGroup::new("Company")
+                        .link(FooterLink::new("About").href("/about"))
+                        .link(FooterLink::new("Jobs").href("/jobs")),
+                ],
+                p { "© 2026 Example Inc." }
+            }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.starts_with(r#"<footer class="footer" style=""><div class="content" style="">Plain footer</div></footer>"#), "{html}");
+    assert!(html.contains(r#"<footer class="footer has-background-dark has-text-dark-invert py-4" style="">Dark footer</footer>"#), "{html}");
+    assert!(html.contains(r#"<footer class="footer has-background-primary-light has-text-primary-dark" style="">Light footer</footer>"#), "{html}");
+    assert!(html.contains(r#"<footer class="footer has-background-info-90 has-text-info-90-invert" style="">Pale footer</footer>"#), "{html}");
+    assert_eq!(html.matches(r#"<div class="column" style=""><p class="has-text-weight-semibold mb-2">"#).count(), 2, "{html}");
+    assert!(html.contains(r#"<p class="has-text-weight-semibold mb-2">Company</p><ul><li><a href="/about">About</a></li><li><a href="/jobs">Jobs</a></li></ul>"#), "{html}");
+    assert!(html.ends_with(r#"</div><p>© 2026 Example Inc.</p></div></footer>"#), "{html}");
+}
+
+#[cfg(feature = "router")]
+mod routed {
+    use super::common;
+    use dioxus::prelude::*;
+    use dioxus_bulma::prelude::*;
+
+    #[derive(Routable, Clone, PartialEq, Debug)]
+    #[rustfmt::skip]
+    enum Route {
+        #[route("/")]
+        Home {},
+    }
+
+    #[component]
+    fn Home() -> Element {
+        rsx! {
+            FooterColumns {
+                groups: vec![
+                    FooterLinkGroup::new("Site")
+                        .link(FooterLink::new("Pricing").to(NavigationTarget::<Route>::Internal(Route::Home {})).href("/ignored"))
+                        .link(FooterLink::new("Status").href("https://status.example.com")),
+                ],
+            }
+        }
+    }
+
+    #[test]
+    fn footer_links_route_through_linkable() {
+        fn app() -> Element {
+            use_hook(|| {
+                let history: std::rc::Rc<dyn dioxus::history::History> =
+                    std::rc::Rc::new(dioxus::history::MemoryHistory::with_initial_path("/"));
+                provide_context(history)
+            });
+            rsx! { Router::<Route> {} }
+        }
+
+        let html = common::render(app);
+        // `to` wins over `href`.
+        assert!(html.contains(r#"<ul><li><a href="/" aria-current="page">Pricing</a></li><li><a href="https://status.example.com">Status</a></li></ul>"#), "{html}");
+    }
+}
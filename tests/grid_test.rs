@@ -0,0 +1,53 @@
+//! Tests for the Bulma 1.0 grid components.
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+use dioxus_bulma::classes;
+
+#[test]
+fn grid_columns_render_has_cols_classes() {
+    assert_eq!(GridColumns::Is4.class(), "has-4-cols");
+    assert_eq!("12".parse::<GridColumns>(), Ok(GridColumns::Is12));
+
+    let cols = Responsive::new(GridColumns::Is2)
+        .tablet(GridColumns::Is4)
+        .desktop(GridColumns::Is6);
+    assert_eq!(
+        classes!["fixed-grid", &cols].as_str(),
+        "fixed-grid has-2-cols has-4-cols-tablet has-6-cols-desktop"
+    );
+}
+
+#[test]
+fn grids_render() {
+    fn app() -> Element {
+        rsx! {
+            Grid { col_min: 12, gap: 1.5,
+                Cell { "One" }
+                Cell { col_span: 2, row_span: 2, "Two by two" }
+            }
+            FixedGrid {
+                cols: Responsive::new(GridColumns::Is2).tablet(GridColumns::Is4),
+                column_gap: 0.5,
+                Cell { col_start: 3, "Third column" }
+                Cell { col_from_end: 1, row_from_end: 1, "Bottom right" }
+            }
+            FixedGrid { auto_count: true, Cell { "Auto" } }
+            FixedGrid { cols: GridColumns::Is3, Cell { row_start: 2, "Second row" } }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.contains(r#"<div class="grid is-col-min-12 is-gap-1.5" style="">"#), "{html}");
+    assert!(html.contains(r#"<div class="cell is-col-span-2 is-row-span-2" style="">Two by two</div>"#), "{html}");
+    assert!(
+        html.contains(r#"<div class="fixed-grid has-2-cols has-4-cols-tablet" style=""><div class="grid is-column-gap-0.5">"#),
+        "{html}"
+    );
+    assert!(html.contains(r#"<div class="cell is-col-start-3" style="">"#), "{html}");
+    assert!(html.contains(r#"<div class="cell is-col-from-end-1 is-row-from-end-1" style="">"#), "{html}");
+    assert!(html.contains(r#"<div class="fixed-grid has-auto-count" style=""><div class="grid">"#), "{html}");
+    assert!(html.contains(r#"<div class="fixed-grid has-3-cols" style="">"#), "{html}");
+    assert!(html.contains(r#"<div class="cell is-row-start-2" style="">"#), "{html}");
+}
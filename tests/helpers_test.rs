@@ -0,0 +1,60 @@
+//! Tests for the typed `helpers` prop.
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+#[test]
+fn helpers_render_bulma_classes() {
+    let helpers = Helpers::new()
+        .margin(Side::X, 3)
+        .padding(Side::All, 9)
+        .margin_auto(Side::Top)
+        .text_align(TextAlignment::Centered)
+        .weight(FontWeight::Bold)
+        .transform(TextTransform::Uppercase)
+        .background(BulmaColor::Light)
+        .text_shade(BulmaColor::Primary, ColorShade::Dark);
+    assert_eq!(
+        helpers.to_class().as_deref(),
+        Some(
+            "mx-3 p-6 mt-auto has-text-centered has-text-weight-bold is-uppercase \
+             has-background-light has-text-primary-dark"
+        )
+    );
+
+    assert_eq!(Helpers::new().to_class(), None);
+    assert!(Helpers::default().is_empty());
+}
+
+#[test]
+fn helpers_support_breakpoints_and_dedupe() {
+    let helpers = Helpers::new()
+        .text_size(Responsive::new(7).desktop(3))
+        .display(Responsive::default().mobile(DisplayMode::Flex))
+        .hidden_at(Breakpoint::Touch)
+        .hidden_at(Breakpoint::Touch)
+        .sr_only();
+    assert_eq!(
+        helpers.classes(),
+        ["is-size-7 is-size-3-desktop", "is-flex-mobile", "is-hidden-touch", "is-sr-only"]
+    );
+    assert_eq!(Helpers::new().text_size(0).to_class().as_deref(), Some("is-size-1"));
+}
+
+#[allow(dead_code)]
+fn _helpers_prop_compiles() -> Element {
+    rsx! {
+        Section {
+            helpers: Helpers::new().padding(Side::Y, 4),
+            Container {
+                helpers: Helpers::new().text_align(TextAlignment::Centered),
+                Notification { helpers: Helpers::new().weight(FontWeight::Light), "Saved" }
+                Button {
+                    helpers: Helpers::new().margin(Side::Right, 2),
+                    class: "extra",
+                    "Save"
+                }
+                Tag { helpers: Helpers::new().transform(TextTransform::Italic), "New" }
+            }
+        }
+    }
+}
@@ -50,5 +50,5 @@ fn _every_component_accepts_id() -> Element {
 
 #[test]
 fn id_prop_compiles_for_components() {
-    assert!(true);
+    // The real assertion is `_every_component_accepts_id` compiling.
 }
@@ -0,0 +1,91 @@
+//! Tests for the shared link rendering.
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+#[test]
+fn link_capable_components_render() {
+    fn app() -> Element {
+        rsx! {
+            Linkable { href: "/docs", class: "custom-link", "Docs" }
+            Linkable { fallback: LinkFallback::Span, "No destination" }
+            Linkable { href: "/ignored", disabled: true, fallback: LinkFallback::Button, "Disabled" }
+            Linkable { href: "/current", aria_current: "page", "Current page" }
+            NavbarItem { href: "/", "Home" }
+            CardFooterItem { href: "/edit", "Edit" }
+            Tag { href: "/tags/rust", "rust" }
+            LevelItem { href: "/stats", "Stats" }
+            LevelItem { "Plain" }
+            Button { disabled: true, "Disabled button" }
+            Pagination {
+                PaginationPrevious { disabled: true, "Previous" }
+                PaginationList {
+                    PaginationLink { current: true, href: "?page=1", "1" }
+                }
+            }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.starts_with(r#"<a class="custom-link" href="/docs">Docs</a><span>No destination</span>"#), "{html}");
+    // Disabled links drop their href and render the fallback.
+    assert!(html.contains(r#"<button disabled="true">Disabled</button>"#), "{html}");
+    assert!(!html.contains("/ignored"), "{html}");
+    assert!(html.contains(r#"<a href="/current" aria-current="page">Current page</a>"#), "{html}");
+    assert!(html.contains(r#"<a class="navbar-item" href="/">Home</a>"#), "{html}");
+    assert!(html.contains(r#"<a class="card-footer-item" href="/edit">Edit</a>"#), "{html}");
+    assert!(html.contains(r#"<a class="tag" href="/tags/rust">rust</a>"#), "{html}");
+    assert!(html.contains(r#"<a class="level-item" href="/stats">Stats</a><div class="level-item">Plain</div>"#), "{html}");
+    assert!(html.contains(r#"<button class="button is-primary" disabled="true">Disabled button</button>"#), "{html}");
+    assert!(html.contains(r#"<a class="pagination-previous">Previous</a>"#), "{html}");
+    assert!(html.contains(r#"<a class="pagination-link is-current" href="?page=1" aria-current="page">1</a>"#), "{html}");
+}
+
+#[cfg(feature = "router")]
+mod routed {
+    use super::common;
+    use dioxus::prelude::*;
+    use dioxus_bulma::prelude::*;
+
+    #[derive(Routable, Clone, PartialEq, Debug)]
+    #[rustfmt::skip]
+    enum Route {
+        #[route("/docs")]
+        Docs {},
+    }
+
+    fn target(path: &str) -> NavigationTarget {
+        NavigationTarget::Internal(path.to_string())
+    }
+
+    #[component]
+    fn Docs() -> Element {
+        rsx! {
+            Linkable { to: target("/blog"), href: "/ignored", class: "routed", "Blog" }
+            Linkable { to: target("/docs"), aria_current: "page", "Docs" }
+            Linkable { to: target("/blog"), disabled: true, fallback: LinkFallback::Span, "Disabled" }
+            NavbarItem { to: target("/blog"), "Navbar blog" }
+        }
+    }
+
+    #[test]
+    fn to_renders_a_router_link() {
+        fn app() -> Element {
+            use_hook(|| {
+                let history: std::rc::Rc<dyn dioxus::history::History> =
+                    std::rc::Rc::new(dioxus::history::MemoryHistory::with_initial_path("/docs"));
+                provide_context(history)
+            });
+            rsx! { Router::<Route> {} }
+        }
+
+        let html = common::render(app);
+        // `to` wins over `href`.
+        assert!(html.starts_with(r#"<a href="/blog" class="routed">Blog</a>"#), "{html}");
+        assert_eq!(html.matches("aria-current").count(), 1, "{html}");
+        assert!(html.contains(r#"<a href="/docs" aria-current="page">Docs</a>"#), "{html}");
+        assert!(html.contains(r#"<span>Disabled</span>"#), "{html}");
+        assert!(html.contains(r#"<a href="/blog" class="navbar-item">Navbar blog</a>"#), "{html}");
+    }
+}
@@ -0,0 +1,58 @@
+//! Tests for the `BulmaClass` trait shared by all modifier enums.
+use dioxus_bulma::classes;
+use dioxus_bulma::components::{
+    BreadcrumbAlignment, BreadcrumbSeparator, ColumnSize, ContainerBreakpoint, DropdownTrigger, ImageSize,
+    PaginationAlignment, TabsAlignment, TabsStyle, TileSize,
+};
+use dioxus_bulma::prelude::*;
+
+fn class_of(modifier: impl BulmaClass) -> String {
+    classes![modifier]
+}
+
+#[test]
+fn modifiers_render_their_bulma_class() {
+    assert_eq!(class_of(BulmaColor::Primary), "is-primary");
+    assert_eq!(class_of(BulmaColor::Custom("brand")), "is-brand");
+    assert_eq!(class_of(BulmaSize::Large), "is-large");
+    assert_eq!(class_of(BulmaTheme::Dark), "theme-dark");
+    assert_eq!(class_of(ColumnSize::ThreeQuarters), "is-three-quarters");
+    assert_eq!(class_of(ContainerBreakpoint::MaxDesktop), "is-max-desktop");
+    assert_eq!(class_of(ImageSize::Is16by9), "is-16by9");
+    assert_eq!(class_of(TileSize::Is4), "is-4");
+    assert_eq!(class_of(TitleSize::Is2), "is-2");
+    assert_eq!(class_of(BreadcrumbSeparator::Arrow), "has-arrow-separator");
+    assert_eq!(class_of(ButtonsAlignment::Centered), "is-centered");
+    assert_eq!(class_of(PaginationAlignment::Right), "is-right");
+    assert_eq!(class_of(TabsAlignment::Centered), "is-centered");
+    assert_eq!(class_of(TabsStyle::ToggleRounded), "is-toggle is-toggle-rounded");
+    assert_eq!(class_of(DropdownTrigger::Hover), "is-hoverable");
+    assert_eq!(class_of(TextAlignment::Justified), "has-text-justified");
+    assert_eq!(class_of(FontWeight::Semibold), "has-text-weight-semibold");
+    assert_eq!(class_of(FontFamily::Code), "is-family-code");
+}
+
+#[test]
+fn default_variants_render_no_class() {
+    assert_eq!(BulmaSize::Normal.name(), "");
+    assert_eq!(class_of(BulmaSize::Normal), "");
+    assert_eq!(class_of(BreadcrumbAlignment::Left), "");
+    assert_eq!(class_of(TabsStyle::Default), "");
+    assert_eq!(class_of(DropdownTrigger::Click), "");
+    assert_eq!(classes![ColumnSize::Half.prefixed("is-offset-"), BulmaSize::Normal.prefixed("is-")], "is-offset-half");
+}
+
+#[test]
+fn display_and_prefixed_variants() {
+    assert_eq!(BulmaColor::Danger.to_string(), BulmaColor::Danger.class());
+    assert_eq!(format!("{}", TabsStyle::Boxed), "is-boxed");
+    assert_eq!(ColumnSize::Two.prefixed("is-offset-").to_string(), "is-offset-2");
+    assert_eq!(BulmaColor::Info.prefixed("has-background-").to_string(), "has-background-info");
+}
+
+#[test]
+fn responsive_modifiers_render_without_helpers() {
+    let size = Responsive::new(ColumnSize::Full).tablet(ColumnSize::Half);
+    assert_eq!(classes![&size], "is-full is-half-tablet");
+    assert_eq!(classes![size.prefixed("is-offset-")], "is-offset-full is-offset-half-tablet");
+}
@@ -0,0 +1,183 @@
+//! Tests for the navbar's self-managed mobile menu.
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+thread_local! {
+    static SEEN_NAVBARS: std::cell::RefCell<Vec<Option<NavbarContext>>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn RecordNavbar() -> Element {
+    let navbar = use_navbar();
+    SEEN_NAVBARS.with(|seen| seen.borrow_mut().push(navbar));
+    rsx! {}
+}
+
+#[test]
+fn navbar_scopes_menu_state() {
+    fn app() -> Element {
+        rsx! {
+            RecordNavbar {}
+            Navbar { auto_close: true,
+                NavbarBrand {
+                    NavbarItem { href: "/", "Home" }
+                    NavbarBurger {}
+                }
+                NavbarMenu {
+                    RecordNavbar {}
+                    NavbarStart { NavbarItem { href: "/docs", "Docs" } }
+                }
+            }
+            Navbar {
+                NavbarMenu { RecordNavbar {} }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    let seen = SEEN_NAVBARS.with(|seen| seen.borrow().clone());
+    assert!(seen[0].is_none());
+    let (mut first, second) = (seen[1].unwrap(), seen[2].unwrap());
+    assert_ne!(first.menu_id(), second.menu_id());
+
+    dom.in_runtime(|| {
+        assert!(!*first.signal().peek());
+        first.toggle();
+        assert!(*first.signal().peek());
+        assert!(!*second.signal().peek());
+        first.close();
+        assert!(!*first.signal().peek());
+    });
+}
+
+#[test]
+fn navbar_dropdowns_render() {
+    fn app() -> Element {
+        rsx! {
+            Navbar {
+                NavbarMenu {
+                    NavbarStart {
+                        NavbarDropdown { label: rsx! { "Docs" },
+                            NavbarItem { href: "/docs/overview", "Overview" }
+                            NavbarDivider {}
+                            NavbarItem { href: "/docs/api", "API" }
+                        }
+                    }
+                    NavbarEnd {
+                        NavbarDropdown {
+                            label: rsx! { "Account" },
+                            trigger: DropdownTrigger::Click,
+                            right: true,
+                            boxed: true,
+                            up: true,
+                            arrowless: true,
+                            NavbarItem { "Sign out" }
+                        }
+                        NavbarLink { href: "/more", arrowless: true, "More" }
+                    }
+                }
+            }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.contains(r#"<div class="navbar-item has-dropdown is-hoverable" style="" id="navbar-dropdown-"#), "{html}");
+    assert!(html.contains(r#"<a class="navbar-link" style="">Docs</a><div class="navbar-dropdown"><a class="navbar-item" href="/docs/overview">Overview</a><hr class="navbar-divider" style=""></hr>"#), "{html}");
+    // Click-triggered dropdowns are not hoverable.
+    assert!(html.contains(r#"<div class="navbar-item has-dropdown has-dropdown-up" style="" id="navbar-dropdown-"#), "{html}");
+    assert!(html.contains(r#"<a class="navbar-link is-arrowless" style="">Account</a><div class="navbar-dropdown is-right is-boxed"><div class="navbar-item">Sign out</div></div>"#), "{html}");
+    assert!(html.contains(r#"<a class="navbar-link is-arrowless" style="" href="/more">More</a>"#), "{html}");
+}
+
+/// Records the messages the navbar sends to the JavaScript it evaluates.
+#[derive(Default)]
+struct RecordingDocument {
+    sent: std::rc::Rc<std::cell::RefCell<Vec<serde_json::Value>>>,
+    owners: std::cell::RefCell<Vec<Owner>>,
+}
+
+struct RecordingEvaluator(std::rc::Rc<std::cell::RefCell<Vec<serde_json::Value>>>);
+
+impl document::Evaluator for RecordingEvaluator {
+    fn send(&self, data: serde_json::Value) -> Result<(), document::EvalError> {
+        self.0.borrow_mut().push(data);
+        Ok(())
+    }
+
+    fn poll_recv(&mut self, _: &mut std::task::Context<'_>) -> std::task::Poll<Result<serde_json::Value, document::EvalError>> {
+        std::task::Poll::Pending
+    }
+
+    fn poll_join(&mut self, _: &mut std::task::Context<'_>) -> std::task::Poll<Result<serde_json::Value, document::EvalError>> {
+        std::task::Poll::Pending
+    }
+}
+
+impl document::Document for RecordingDocument {
+    fn eval(&self, _js: String) -> document::Eval {
+        let owner = UnsyncStorage::owner();
+        let evaluator: Box<dyn document::Evaluator> = Box::new(RecordingEvaluator(self.sent.clone()));
+        let eval = document::Eval::new(owner.insert(evaluator));
+        self.owners.borrow_mut().push(owner);
+        eval
+    }
+}
+
+thread_local! {
+    static SENT: std::cell::RefCell<Option<std::rc::Rc<std::cell::RefCell<Vec<serde_json::Value>>>>> =
+        const { std::cell::RefCell::new(None) };
+    static DROPDOWN_STATE: std::cell::Cell<Option<(Signal<DropdownTrigger>, Signal<bool>)>> = const { std::cell::Cell::new(None) };
+}
+
+#[test]
+fn click_dropdown_listener_restarts_and_stops() {
+    fn app() -> Element {
+        use_hook(|| {
+            let document = RecordingDocument::default();
+            SENT.with(|sent| *sent.borrow_mut() = Some(document.sent.clone()));
+            provide_context(std::rc::Rc::new(document) as std::rc::Rc<dyn document::Document>)
+        });
+        let trigger = use_signal(|| DropdownTrigger::Click);
+        let shown = use_signal(|| true);
+        use_hook(|| DROPDOWN_STATE.with(|state| state.set(Some((trigger, shown)))));
+        rsx! {
+            if shown() {
+                NavbarDropdown { label: rsx! { "Account" }, trigger: trigger(), id: "account",
+                    NavbarItem { "Sign out" }
+                }
+            }
+        }
+    }
+
+    fn settle(dom: &mut VirtualDom) {
+        for _ in 0..3 {
+            dom.process_events();
+            dom.render_immediate_to_vec();
+        }
+    }
+
+    let sent = || SENT.with(|sent| sent.borrow().as_ref().unwrap().borrow().clone());
+    use serde_json::json;
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom);
+    assert_eq!(sent(), [json!("account")]);
+
+    let (mut trigger, mut shown) = DROPDOWN_STATE.with(|state| state.get()).unwrap();
+    dom.in_runtime(|| trigger.set(DropdownTrigger::Hover));
+    settle(&mut dom);
+    assert_eq!(sent(), [json!("account"), json!(null)]);
+
+    dom.in_runtime(|| trigger.set(DropdownTrigger::Click));
+    settle(&mut dom);
+    assert_eq!(sent(), [json!("account"), json!(null), json!("account")]);
+
+    dom.in_runtime(|| shown.set(false));
+    settle(&mut dom);
+    assert_eq!(sent(), [json!("account"), json!(null), json!("account"), json!(null)]);
+}
@@ -0,0 +1,138 @@
+//! Tests for page windows, `use_pagination` and the `Paginator`.
+
+use dioxus::prelude::*;
+use dioxus_bulma::components::PaginationAlignment;
+use dioxus_bulma::prelude::*;
+use PageItem::{Ellipsis, Page};
+
+mod common;
+
+thread_local! {
+    static SEEN_PAGINATION: std::cell::Cell<Option<PaginationState>> = const { std::cell::Cell::new(None) };
+}
+
+#[test]
+fn window_shows_bounds_and_siblings() {
+    assert_eq!(page_window(1, 10, 2), [Page(1), Page(2), Page(3), Ellipsis, Page(10)]);
+    assert_eq!(
+        page_window(6, 10, 2),
+        [Page(1), Ellipsis, Page(4), Page(5), Page(6), Page(7), Page(8), Page(9), Page(10)]
+    );
+    assert_eq!(page_window(10, 10, 1), [Page(1), Ellipsis, Page(9), Page(10)]);
+    assert_eq!(page_window(1, 1, 2), [Page(1)]);
+    assert_eq!(page_window(1, 0, 2), []);
+}
+
+#[test]
+fn window_never_hides_a_single_page() {
+    assert_eq!(page_window(4, 7, 1), [Page(1), Page(2), Page(3), Page(4), Page(5), Page(6), Page(7)]);
+    assert_eq!(page_window(5, 9, 1), [Page(1), Ellipsis, Page(4), Page(5), Page(6), Ellipsis, Page(9)]);
+}
+
+#[test]
+fn window_clamps_out_of_range_pages() {
+    assert_eq!(page_window(0, 3, 0), [Page(1), Page(2), Page(3)]);
+    assert_eq!(page_window(99, 5, 0), [Page(1), Ellipsis, Page(5)]);
+}
+
+#[test]
+fn use_pagination_tracks_and_clamps_the_page() {
+    fn app() -> Element {
+        let pagination = use_pagination(45, 10);
+        SEEN_PAGINATION.with(|seen| seen.set(Some(pagination)));
+        rsx! {
+            Paginator { pagination }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let mut pagination = SEEN_PAGINATION.with(|seen| seen.get()).unwrap();
+
+    dom.in_runtime(|| {
+        assert_eq!(pagination.total_pages(), 5);
+        assert_eq!(pagination.page(), 1);
+        assert_eq!(pagination.item_range(), 0..10);
+        assert!(!pagination.has_previous());
+
+        pagination.previous();
+        assert_eq!(pagination.page(), 1);
+        pagination.set_page(99);
+        assert_eq!(pagination.page(), 5);
+        assert_eq!(pagination.item_range(), 40..45);
+        assert!(!pagination.has_next());
+        pagination.next();
+        assert_eq!(pagination.page(), 5);
+        pagination.previous();
+        assert_eq!(pagination.page(), 4);
+        assert_eq!(pagination.window(0), [Page(1), Ellipsis, Page(4), Page(5)]);
+    });
+}
+
+#[test]
+fn empty_list_has_one_page() {
+    fn app() -> Element {
+        let pagination = use_pagination(0, 0);
+        SEEN_PAGINATION.with(|seen| seen.set(Some(pagination)));
+        rsx! {}
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let pagination = SEEN_PAGINATION.with(|seen| seen.get()).unwrap();
+
+    dom.in_runtime(|| {
+        assert_eq!(pagination.total_pages(), 1);
+        assert_eq!(pagination.page_size(), 1);
+        assert_eq!(pagination.item_range(), 0..0);
+    });
+}
+
+#[test]
+fn controlled_paginator_renders() {
+    fn app() -> Element {
+        rsx! {
+            Paginator {
+                page: 3,
+                total_pages: 12,
+                siblings: 1,
+                on_page_change: |_page: usize| {},
+                href: |page: usize| format!("?page={page}"),
+                previous_label: "Back",
+                next_label: "Forward",
+                alignment: PaginationAlignment::Centered,
+                rounded: true,
+            }
+            Paginator { page: 1, total_pages: 12, siblings: 1, href: |page: usize| format!("?page={page}") }
+            Paginator { page: 12, total_pages: 12, siblings: 1, href: |page: usize| format!("?page={page}") }
+            Paginator {}
+        }
+    }
+
+    let html = common::render(app);
+    let navs: Vec<&str> = html.split("</nav>").filter(|nav| !nav.is_empty()).collect();
+    assert_eq!(navs.len(), 4, "{html}");
+
+    let (middle, first, last, empty) = (navs[0], navs[1], navs[2], navs[3]);
+    assert!(middle.starts_with(r#"<nav class="pagination is-centered is-rounded""#), "{middle}");
+    assert!(middle.contains(r#"<a class="pagination-previous" href="?page=2">Back</a><a class="pagination-next" href="?page=4">Forward</a>"#), "{middle}");
+    assert!(middle.contains(r#"<a class="pagination-link is-current" href="?page=3" aria-current="page">3</a>"#), "{middle}");
+    assert_eq!(middle.matches("is-current").count(), 1, "{middle}");
+    assert_eq!(middle.matches(r#"aria-current="page""#).count(), 1, "{middle}");
+    for page in [1, 2, 4, 12] {
+        assert!(middle.contains(&format!(r#"<a class="pagination-link" href="?page={page}">{page}</a>"#)), "{middle}");
+    }
+    assert_eq!(middle.matches(r#"<li><span class="pagination-ellipsis" style="">…</span></li>"#).count(), 1, "{middle}");
+
+    assert!(first.contains(r#"<a class="pagination-previous is-disabled">Previous</a><a class="pagination-next" href="?page=2">Next</a>"#), "{first}");
+    assert!(first.contains(r#"<a class="pagination-link is-current" href="?page=1" aria-current="page">1</a>"#), "{first}");
+    assert!(first.contains(r#"<span class="pagination-ellipsis" style="">…</span></li><li><a class="pagination-link" href="?page=12">12</a>"#), "{first}");
+
+    assert!(last.contains(r#"<a class="pagination-previous" href="?page=11">Previous</a><a class="pagination-next is-disabled">Next</a>"#), "{last}");
+    assert!(last.contains(r#"<a class="pagination-link" href="?page=1">1</a></li><li><span class="pagination-ellipsis" style="">…</span>"#), "{last}");
+    assert!(last.contains(r#"<a class="pagination-link is-current" href="?page=12" aria-current="page">12</a>"#), "{last}");
+
+    // Without props a single disabled page is shown.
+    assert!(empty.contains(r#"<a class="pagination-previous is-disabled">Previous</a><a class="pagination-next is-disabled">Next</a>"#), "{empty}");
+    assert!(empty.ends_with(r#"<li><a class="pagination-link is-current" aria-current="page">1</a></li></ul>"#), "{empty}");
+}
@@ -0,0 +1,139 @@
+//! Tests for state kept in the URL's query string.
+#![cfg(feature = "router")]
+
+use std::rc::Rc;
+
+use dioxus::history::{History, MemoryHistory};
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+mod common;
+
+type SeenStates = (QueryState<usize>, QueryState<String>, QueryState<u8>, PaginationState);
+
+thread_local! {
+    static HISTORY: std::cell::RefCell<Option<Rc<dyn History>>> = const { std::cell::RefCell::new(None) };
+    static SEEN_STATES: std::cell::Cell<Option<SeenStates>> = const { std::cell::Cell::new(None) };
+    static INITIAL_PATH: std::cell::Cell<&'static str> = const { std::cell::Cell::new("/devices?page=3&q=hello%20world&sort=x") };
+}
+
+#[derive(Routable, Clone, PartialEq, Debug)]
+#[rustfmt::skip]
+enum Route {
+    // The raw query has to be part of the route, or the router drops it.
+    #[route("/devices?:..query")]
+    Devices { query: String },
+}
+
+#[component]
+fn Devices(query: String) -> Element {
+    let page = use_query_state("page", 1usize);
+    let search = use_query_state("q", String::new());
+    let sort = use_query_state("sort", 0u8);
+    let pagination = use_query_pagination("page", 100, 10);
+    SEEN_STATES.with(|seen| seen.set(Some((page, search, sort, pagination))));
+
+    rsx! {
+        Input { query_key: "q" }
+        Tabs { query_key: "tab", default_tab: "overview",
+            Tab { value: "overview", "Overview" }
+            Tab { value: "logs", "Logs" }
+        }
+        Paginator { pagination }
+    }
+}
+
+fn app() -> Element {
+    use_hook(|| {
+        let history: Rc<dyn History> =
+            Rc::new(MemoryHistory::with_initial_path(INITIAL_PATH.with(|path| path.get())));
+        HISTORY.with(|seen| *seen.borrow_mut() = Some(history.clone()));
+        provide_context(history)
+    });
+    rsx! { Router::<Route> {} }
+}
+
+fn current_route() -> String {
+    HISTORY.with(|history| history.borrow().as_ref().unwrap().current_route())
+}
+
+#[test]
+fn query_state_reads_and_writes_the_url() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let (mut page, mut search, sort, pagination) = SEEN_STATES.with(|seen| seen.get()).unwrap();
+
+    // Writing the URL goes through the router, which needs a scope, as in an
+    // event handler.
+    dom.in_scope(ScopeId::APP, || {
+        assert_eq!(page.get(), 3);
+        assert_eq!(search.get(), "hello world");
+        // Unparsable values fall back to the default.
+        assert_eq!(sort.get(), 0);
+        assert_eq!(pagination.page(), 3);
+        assert_eq!(pagination.item_range(), 20..30);
+
+        page.set(4);
+        assert_eq!(current_route(), "/devices?q=hello%20world&sort=x&page=4");
+        search.set("a&b c".to_string());
+        assert_eq!(current_route(), "/devices?sort=x&page=4&q=a%26b%20c");
+        page.reset();
+        assert_eq!(current_route(), "/devices?sort=x&q=a%26b%20c");
+        assert_eq!(page.key(), "page");
+    });
+}
+
+#[test]
+fn query_pagination_writes_the_page() {
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let (_, _, _, mut pagination) = SEEN_STATES.with(|seen| seen.get()).unwrap();
+
+    dom.in_scope(ScopeId::APP, || {
+        pagination.next();
+        assert_eq!(pagination.page(), 4);
+        assert_eq!(current_route(), "/devices?q=hello%20world&sort=x&page=4");
+        pagination.set_page(1);
+        assert_eq!(current_route(), "/devices?q=hello%20world&sort=x");
+    });
+}
+
+#[test]
+fn query_bound_components_restore_from_the_url() {
+    let html = common::render(app);
+    assert!(html.contains(r#"<input type="text" class="input" style="" value="hello world""#), "{html}");
+    // No `?tab=`, so the default tab is active.
+    assert!(html.contains(r#"<li class="is-active" style=""><a aria-current="page">Overview</a></li><li class="" style=""><a>Logs</a></li>"#), "{html}");
+
+    INITIAL_PATH.with(|path| path.set("/devices?tab=logs&q=abc"));
+    let html = common::render(app);
+    assert!(html.contains(r#"value="abc""#), "{html}");
+    assert!(html.contains(r#"<li class="" style=""><a>Overview</a></li><li class="is-active" style=""><a aria-current="page">Logs</a></li>"#), "{html}");
+}
+
+#[test]
+fn query_state_outside_router_stays_in_memory() {
+    fn app() -> Element {
+        let page = use_query_state("page", 1usize);
+        let pagination = use_query_pagination("page", 30, 10);
+        SEEN_STATES.with(|seen| {
+            seen.set(Some((page, use_query_state("q", String::new()), use_query_state("sort", 0), pagination)))
+        });
+        rsx! {
+            Input { query_key: "q" }
+            Tabs { query_key: "tab", Tab { value: "overview", "Overview" } }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    let (mut page, _, _, mut pagination) = SEEN_STATES.with(|seen| seen.get()).unwrap();
+
+    dom.in_runtime(|| {
+        assert_eq!(page.get(), 1);
+        page.set(2);
+        assert_eq!(page.get(), 2);
+        pagination.set_page(3);
+        assert_eq!(pagination.page(), 3);
+    });
+}
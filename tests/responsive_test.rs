@@ -0,0 +1,76 @@
+//! Tests for per-breakpoint `Responsive<T>` layout props.
+use dioxus::prelude::*;
+use dioxus_bulma::components::ColumnSize;
+use dioxus_bulma::prelude::*;
+
+#[test]
+fn responsive_renders_breakpoint_suffixes() {
+    let size = Responsive::new(ColumnSize::Full)
+        .tablet(ColumnSize::Half)
+        .desktop(ColumnSize::OneThird);
+    assert_eq!(
+        size.to_class(ColumnSize::as_str).as_deref(),
+        Some("is-full is-half-tablet is-one-third-desktop")
+    );
+
+    // Later values for the same breakpoint replace earlier ones.
+    let size = Responsive::default().mobile(ColumnSize::Two).mobile(ColumnSize::Three);
+    assert_eq!(size.get(Breakpoint::Mobile), Some(&ColumnSize::Three));
+    assert_eq!(size.base(), None);
+    assert_eq!(size.to_class(ColumnSize::as_str).as_deref(), Some("is-3-mobile"));
+
+    assert!(Responsive::<ColumnSize>::default().is_empty());
+    assert_eq!(Responsive::<ColumnSize>::default().to_class(ColumnSize::as_str), None);
+}
+
+#[test]
+fn visibility_and_text_alignment_classes() {
+    let hidden = Responsive::default().mobile(true).fullhd(true);
+    assert_eq!(hidden.to_hidden_class().as_deref(), Some("is-hidden-mobile is-hidden-fullhd"));
+    assert_eq!(Responsive::from(false).to_hidden_class(), None);
+    assert_eq!(Responsive::from(true).to_hidden_class().as_deref(), Some("is-hidden"));
+
+    let align = Responsive::new(TextAlignment::Centered).at(Breakpoint::Desktop, TextAlignment::Left);
+    assert_eq!(
+        align.to_text_align_class().as_deref(),
+        Some("has-text-centered has-text-left-desktop")
+    );
+}
+
+#[test]
+fn false_overrides_show_hidden_elements_again() {
+    let hidden = |value: Responsive<bool>| value.to_hidden_class();
+    assert_eq!(hidden(Responsive::new(true).desktop(false)).as_deref(), Some("is-hidden-touch"));
+    assert_eq!(hidden(Responsive::new(true).tablet(false)).as_deref(), Some("is-hidden-mobile"));
+    assert_eq!(hidden(Responsive::new(true).mobile(false)).as_deref(), Some("is-hidden-tablet"));
+    assert_eq!(hidden(Responsive::new(true).touch(false)).as_deref(), Some("is-hidden-desktop"));
+    assert_eq!(
+        hidden(Responsive::new(true).tablet(false).widescreen(true)).as_deref(),
+        Some("is-hidden-mobile is-hidden-widescreen")
+    );
+    assert_eq!(
+        hidden(Responsive::new(false).tablet(true).desktop(false).widescreen(true).fullhd(false)).as_deref(),
+        Some("is-hidden-tablet-only is-hidden-widescreen-only")
+    );
+    assert_eq!(hidden(Responsive::new(true).touch(false).widescreen(false)).as_deref(), Some("is-hidden-desktop-only"));
+    assert_eq!(hidden(Responsive::new(false).desktop(true).fullhd(false)).as_deref(), Some("is-hidden-desktop-only is-hidden-widescreen-only"));
+    assert_eq!(hidden(Responsive::new(true).mobile(false).tablet(false)), None);
+}
+
+#[allow(dead_code)]
+fn _layout_props_accept_plain_and_responsive_values() -> Element {
+    rsx! {
+        Columns {
+            text_align: TextAlignment::Centered,
+            Column { size: ColumnSize::Half, offset: ColumnSize::OneQuarter, "Plain" }
+            Column {
+                size: Responsive::new(ColumnSize::Full).tablet(ColumnSize::Half),
+                offset: Responsive::default().widescreen(ColumnSize::Two),
+                hidden: Responsive::default().mobile(true),
+                "Responsive"
+            }
+        }
+        Container { hidden: true, "Never shown" }
+        Section { text_align: Responsive::default().touch(TextAlignment::Centered), "x" }
+    }
+}
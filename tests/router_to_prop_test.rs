@@ -40,11 +40,19 @@ fn _accepts_route_directly() -> Element {
         PaginationPrevious { to: Route::DeviceList {} }
         PaginationNext { to: Route::DeviceList {} }
         Tab { to: Route::DeviceList {}, "Devices" }
+        NavbarItem { to: Route::DeviceList {}, "Devices" }
+        CardFooterItem { to: Route::DeviceList {}, "Devices" }
+        Tag { to: Route::DeviceList {}, "Devices" }
+        LevelItem { to: Route::DeviceList {}, "Devices" }
+        Linkable { to: Route::DeviceList {}, class: "custom-link", "Devices" }
+        FooterColumns {
+            groups: vec![FooterLinkGroup::new("Fleet").link(FooterLink::new("Devices").to(Route::DeviceList {}))],
+        }
+        Paginator { total_pages: 3, to: |_page: usize| NavigationTarget::from(Route::DeviceList {}) }
     }
 }
 
 #[test]
 fn route_directly_compiles() {
     // The real assertion is the function above compiling.
-    assert!(true);
 }
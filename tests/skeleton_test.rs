@@ -0,0 +1,72 @@
+//! Tests for skeleton loading states.
+mod common;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+
+#[allow(dead_code)]
+fn _skeleton_props() -> Element {
+    rsx! {
+        Button { skeleton: true, "Save" }
+        Tag { skeleton: true, "Draft" }
+        BulmaTitle { skeleton: true, "Loading title" }
+        BulmaSubtitle { skeleton: true, "Loading subtitle" }
+        Image { skeleton: true, size: dioxus_bulma::components::ImageSize::Is128x128, img { src: "avatar.png" } }
+        Icon { skeleton: true, i { class: "fas fa-user" } }
+        Input { skeleton: true, placeholder: "Name" }
+        Textarea { skeleton: true }
+        Card { skeleton: true, CardContent { "Card body" } }
+        Media { skeleton: true, MediaContent { "Media body" } }
+    }
+}
+
+#[test]
+fn skeleton_components_render() {
+    fn app() -> Element {
+        rsx! {
+            SkeletonBlock {}
+            SkeletonBlock { style: "height: 8rem", "Sized by content" }
+            SkeletonLines {}
+            SkeletonLines { lines: 3, helpers: Helpers::new().margin(Side::Bottom, 4) }
+            Button { skeleton: true, "Save" }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.contains(r#"<div class="skeleton-block" style="height: 8rem">Sized by content</div>"#));
+    assert_eq!(html.matches(r#"<div class="skeleton-lines mb-4""#).count(), 1);
+    assert!(html.contains(r#"<button class="button is-primary is-skeleton">Save</button>"#));
+}
+
+#[test]
+fn skeleton_lines_render_one_div_per_line() {
+    fn app() -> Element {
+        rsx! {
+            SkeletonLines { lines: 3 }
+        }
+    }
+
+    assert_eq!(common::render(app), r#"<div class="skeleton-lines" style=""><div></div><div></div><div></div></div>"#);
+}
+
+#[test]
+fn skeleton_props_add_is_skeleton() {
+    fn app() -> Element {
+        rsx! {
+            Tag { skeleton: true, "Draft" }
+            Input { skeleton: true, placeholder: "Name" }
+            Textarea { skeleton: true }
+            Card { skeleton: true, CardContent { "Card body" } }
+            Media { skeleton: true, MediaContent { "Media body" } }
+            Button { "Plain" }
+        }
+    }
+
+    let html = common::render(app);
+    assert!(html.contains(r#"<span class="tag is-skeleton">Draft</span>"#), "{html}");
+    assert!(html.contains(r#"class="input is-skeleton""#), "{html}");
+    assert!(html.contains(r#"class="textarea is-skeleton""#), "{html}");
+    assert!(html.contains(r#"class="card is-skeleton""#), "{html}");
+    assert!(html.contains(r#"class="media is-skeleton""#), "{html}");
+    assert!(html.contains(r#"<button class="button is-primary">Plain</button>"#), "{html}");
+}
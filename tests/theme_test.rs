@@ -0,0 +1,270 @@
+//! Tests for runtime theme switching through `use_bulma_theme` and theme
+//! persistence via `ThemeStorage`.
+mod common;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_bulma::prelude::*;
+use dioxus_bulma::ThemeStore;
+
+#[derive(Clone, Default)]
+struct MemoryStorage(Rc<Cell<Option<BulmaTheme>>>);
+
+impl ThemeStorage for MemoryStorage {
+    fn load(&self) -> Option<BulmaTheme> {
+        self.0.get()
+    }
+
+    fn save(&self, theme: BulmaTheme) {
+        self.0.set(Some(theme));
+    }
+}
+
+#[test]
+fn theme_names_round_trip() {
+    for theme in [BulmaTheme::Auto, BulmaTheme::Light, BulmaTheme::Dark] {
+        assert_eq!(BulmaTheme::from_name(theme.variant_name()), Ok(theme));
+    }
+    assert_eq!(BulmaTheme::Dark.as_class(), "theme-dark");
+    assert!(BulmaTheme::from_name("sepia").is_err());
+}
+
+#[test]
+fn theme_store_delegates_to_storage() {
+    let memory = MemoryStorage::default();
+    let store = ThemeStore::from(memory.clone());
+
+    assert_eq!(store.load(), None);
+    store.save(BulmaTheme::Dark);
+    assert_eq!(memory.0.get(), Some(BulmaTheme::Dark));
+    assert_eq!(store.load(), Some(BulmaTheme::Dark));
+    assert!(store == store.clone());
+    assert!(store != ThemeStore::none());
+    assert_eq!(ThemeStore::none().load(), None);
+}
+
+#[allow(dead_code)]
+fn _provider_accepts_storage() -> Element {
+    rsx! {
+        BulmaProvider {
+            theme: BulmaTheme::Light,
+            storage: MemoryStorage::default(),
+            ThemeToggle {}
+        }
+    }
+}
+
+#[component]
+fn ThemeToggle() -> Element {
+    let mut theme = use_bulma_theme();
+
+    rsx! {
+        Button {
+            onclick: move |_| theme.toggle(),
+            if theme.theme() == BulmaTheme::Dark { "Light mode" } else { "Dark mode" }
+        }
+    }
+}
+
+#[test]
+fn css_source_hrefs() {
+    assert_eq!(BulmaCssSource::default(), BulmaCssSource::Bundled);
+    let bundled = BulmaCssSource::Bundled.href().unwrap();
+    assert!(bundled.contains("bulma") && bundled.ends_with(".css"), "{bundled}");
+    assert_eq!(
+        BulmaCssSource::Cdn("1.0.4".into()).href().as_deref(),
+        Some("https://cdn.jsdelivr.net/npm/bulma@1.0.4/css/bulma.min.css")
+    );
+    assert_eq!(
+        BulmaCssSource::Url("/static/bulma.css".into()).href().as_deref(),
+        Some("/static/bulma.css")
+    );
+    assert_eq!(BulmaCssSource::Inline(".button {}".into()).href(), None);
+}
+
+#[allow(dead_code)]
+fn _provider_accepts_css_source() -> Element {
+    rsx! {
+        BulmaProvider {
+            css_source: BulmaCssSource::Url("https://intranet.example/bulma.min.css".into()),
+            css_integrity: "sha384-abc",
+            "Mirrored"
+        }
+        BulmaProvider {
+            css_source: BulmaCssSource::Inline(".box { padding: 1rem; }".into()),
+            "Inline"
+        }
+    }
+}
+
+#[test]
+fn theme_config_generates_css_vars() {
+    assert_eq!(BulmaThemeConfig::default().to_css_vars(), "");
+
+    let config = BulmaThemeConfig {
+        primary: Some(ColorHsl::new(265.0, 70.0, 55.0)),
+        danger: Some(ColorHsl::hue(5.0)),
+        radius: Some("0.25rem".into()),
+        font_family: Some("Inter, sans-serif".into()),
+        ..Default::default()
+    };
+    assert_eq!(
+        config.to_css_vars(),
+        "--bulma-primary-h: 265deg; --bulma-primary-s: 70%; --bulma-primary-l: 55%; \
+         --bulma-danger-h: 5deg; \
+         --bulma-radius: 0.25rem; --bulma-family-primary: Inter, sans-serif; "
+    );
+    assert_eq!(
+        BulmaThemeConfig { column_gap: Some("1rem".into()), ..Default::default() }.to_stylesheet(":root"),
+        ":root { --bulma-column-gap: 1rem; }"
+    );
+}
+
+#[test]
+fn custom_colors_behave_like_builtin_colors() {
+    const BRAND: CustomColor = CustomColor::new("brand", 330.0, 80.0, 50.0);
+
+    assert_eq!(BRAND.color(), BulmaColor::Custom("brand"));
+    assert_eq!(BRAND.color().as_str(), "brand");
+
+    let vars = BRAND.to_css_vars();
+    assert!(vars.contains("--bulma-brand-h: 330deg;"));
+    assert!(vars.contains("--bulma-brand-invert-l: 100%;"));
+    assert!(vars.contains("--bulma-brand: hsl(330deg, 80%, 50%);"));
+
+    let rules = BRAND.to_css_rules();
+    for selector in [".button.is-brand", ".tag.is-brand", ".notification.is-brand", ".hero.is-brand", ".input.is-brand"] {
+        assert!(rules.contains(selector), "missing {selector}");
+    }
+
+    let config = BulmaThemeConfig { custom_colors: vec![BRAND], ..Default::default() };
+    let stylesheet = config.to_stylesheet(":root");
+    assert!(stylesheet.starts_with(":root { --bulma-brand-h: 330deg;"));
+    assert!(stylesheet.contains(".button.is-brand {"));
+}
+
+#[test]
+fn auto_theme_resolves_to_os_scheme() {
+    assert_eq!(BulmaTheme::Auto.resolve(true), BulmaTheme::Dark);
+    assert_eq!(BulmaTheme::Auto.resolve(false), BulmaTheme::Light);
+    assert_eq!(BulmaTheme::Light.resolve(true), BulmaTheme::Light);
+    assert_eq!(BulmaTheme::Dark.resolve(false), BulmaTheme::Dark);
+}
+
+thread_local! {
+    static SEEN_THEMES: std::cell::RefCell<Vec<BulmaTheme>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn RecordTheme() -> Element {
+    let theme = use_bulma_theme();
+    SEEN_THEMES.with(|seen| seen.borrow_mut().push(theme.effective_theme()));
+    rsx! { {theme.effective_theme().variant_name()} }
+}
+
+#[test]
+fn theme_scope_overrides_the_enclosing_theme() {
+    fn app() -> Element {
+        rsx! {
+            BulmaProvider {
+                theme: BulmaTheme::Light,
+                RecordTheme {}
+                ThemeScope {
+                    theme: BulmaTheme::Dark,
+                    RecordTheme {}
+                    // No theme: inherits the dark region, only overrides variables.
+                    ThemeScope {
+                        config: BulmaThemeConfig { radius: Some("0".into()), ..Default::default() },
+                        RecordTheme {}
+                    }
+                }
+            }
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+
+    SEEN_THEMES.with(|seen| {
+        assert_eq!(*seen.borrow(), [BulmaTheme::Light, BulmaTheme::Dark, BulmaTheme::Dark]);
+    });
+}
+
+#[test]
+fn auto_theme_leaves_data_theme_unset_until_the_os_scheme_is_known() {
+    fn app() -> Element {
+        rsx! {
+            BulmaProvider {
+                theme: BulmaTheme::Auto,
+                ThemeScope { theme: BulmaTheme::Auto, "auto" }
+                ThemeScope { theme: BulmaTheme::Dark, "dark" }
+            }
+        }
+    }
+
+    // Without JavaScript nothing reports the OS scheme, so Bulma's own
+    // `prefers-color-scheme` rules have to decide.
+    let html = common::render(app);
+    assert!(html.contains(r#"<div class="theme-auto" style="min-height: 100vh;">"#), "{html}");
+    assert!(html.contains(r#"<div class="theme-auto" style="background-color: var(--bulma-scheme-main); color: var(--bulma-text); ">auto</div>"#), "{html}");
+    assert!(html.contains(r#"<div class="theme-dark" data-theme="dark" style="background-color"#), "{html}");
+
+    fn light() -> Element {
+        rsx! { BulmaProvider { theme: BulmaTheme::Light, "light" } }
+    }
+    let html = common::render(light);
+    assert!(html.contains(r#"<div class="theme-light" data-theme="light" style="min-height: 100vh;">"#), "{html}");
+}
+
+thread_local! {
+    static SCOPE_THEME: Cell<Option<Signal<Option<BulmaTheme>>>> = const { Cell::new(None) };
+    static SEEN_CONTEXTS: std::cell::RefCell<Vec<BulmaThemeContext>> = const { std::cell::RefCell::new(Vec::new()) };
+}
+
+#[component]
+fn RecordContext() -> Element {
+    let theme = use_bulma_theme();
+    use_hook(|| SEEN_CONTEXTS.with(|seen| seen.borrow_mut().push(theme)));
+    rsx! {}
+}
+
+#[test]
+fn theme_scope_without_a_theme_never_writes_the_parent_theme() {
+    fn app() -> Element {
+        let scope_theme = use_signal(|| None);
+        use_hook(|| SCOPE_THEME.with(|seen| seen.set(Some(scope_theme))));
+        rsx! {
+            BulmaProvider {
+                theme: BulmaTheme::Light,
+                storage: MemoryStorage::default(),
+                RecordContext {}
+                ThemeScope { theme: scope_theme(), RecordContext {} }
+            }
+        }
+    }
+
+    fn settle(dom: &mut VirtualDom) {
+        // Render the dirty scopes, then run the effects they queued.
+        for _ in 0..3 {
+            dom.process_events();
+            dom.render_immediate_to_vec();
+        }
+    }
+
+    let mut dom = VirtualDom::new(app);
+    dom.rebuild_in_place();
+    settle(&mut dom);
+
+    let mut scope_theme = SCOPE_THEME.with(|seen| seen.get()).unwrap();
+    dom.in_runtime(|| scope_theme.set(Some(BulmaTheme::Dark)));
+    settle(&mut dom);
+
+    let seen = SEEN_CONTEXTS.with(|seen| seen.borrow().clone());
+    assert_eq!(seen.len(), 2);
+    dom.in_runtime(|| {
+        assert_eq!(seen[0].theme(), BulmaTheme::Light);
+        assert_eq!(seen[1].theme(), BulmaTheme::Light);
+    });
+}